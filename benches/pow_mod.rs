@@ -0,0 +1,129 @@
+//! Benchmarks `NeutrosophicNumber::pow_mod` across security parameter sizes,
+//! `pow_mod_windowed` across window sizes at 2048 bits, the integer backends, and
+//! the pure-real fast path against the general formula.
+//!
+//! Run with `cargo bench --bench pow_mod`. Add `--features rug` to compare the GMP
+//! backend against `num-bigint` in the `pow_mod_backend_4096` group.
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use n1rzkp::neutrosophic_numbers::{
+    NeutrosophicNumber, generate_random_neutrosophic, generate_random_neutrosophic_exact,
+};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::hint::black_box;
+
+/// Bit sizes of the base, exponent, and modulus components.
+const BIT_SIZES: [usize; 4] = [512, 1024, 2048, 4096];
+
+/// Builds a `(base, exponent, modulus)` triple of the given bit size.
+///
+/// The modulus has exactly `bits`-bit components, so `p1` and `p1+p2` are nonzero
+/// and every size really has the requested number of bits.
+fn inputs(
+    rng: &mut StdRng,
+    bits: usize,
+) -> (NeutrosophicNumber, NeutrosophicNumber, NeutrosophicNumber) {
+    let base = generate_random_neutrosophic(rng, bits);
+    let exp = generate_random_neutrosophic(rng, bits);
+    let modulus = generate_random_neutrosophic_exact(rng, bits);
+    (base, exp, modulus)
+}
+
+fn bench_pow_mod(c: &mut Criterion) {
+    // A fixed seed keeps the inputs identical across runs.
+    let mut rng = StdRng::seed_from_u64(0x6e31_727a_6b70);
+    let mut group = c.benchmark_group("pow_mod");
+    group.throughput(Throughput::Elements(1));
+    for bits in BIT_SIZES {
+        if bits >= 2048 {
+            group.sample_size(10);
+        }
+        let (base, exp, modulus) = inputs(&mut rng, bits);
+        group.bench_with_input(BenchmarkId::from_parameter(bits), &bits, |b, _| {
+            b.iter(|| black_box(&base).pow_mod(black_box(&exp), black_box(&modulus)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_pow_mod_windowed(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(0x6e31_727a_6b70);
+    let (base, exp, modulus) = inputs(&mut rng, 2048);
+    let mut group = c.benchmark_group("pow_mod_windowed_2048");
+    group.throughput(Throughput::Elements(1));
+    group.sample_size(10);
+    for window in 1..=6 {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(window),
+            &window,
+            |b, &window| {
+                b.iter(|| {
+                    black_box(&base).pow_mod_windowed(black_box(&exp), black_box(&modulus), window)
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_pow_mod_backend(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(0x6e31_727a_6b70);
+    let (base, exp, modulus) = inputs(&mut rng, 4096);
+    let mut group = c.benchmark_group("pow_mod_backend_4096");
+    group.throughput(Throughput::Elements(1));
+    group.sample_size(10);
+    // The `pow_mod` formula spelled out with `BigInt::modpow`, whatever the backend.
+    group.bench_function("num-bigint", |b| {
+        b.iter(|| {
+            let term1 = base.a.modpow(&exp.a, &modulus.a);
+            let base_sum = &base.a + &base.b;
+            let exp_sum = &exp.a + &exp.b;
+            let modulus_sum = &modulus.a + &modulus.b;
+            let term2 = base_sum.modpow(&exp_sum, &modulus_sum);
+            NeutrosophicNumber::new(term1.clone(), term2 - term1)
+        })
+    });
+    let backend = if cfg!(feature = "rug") {
+        "rug"
+    } else {
+        "default"
+    };
+    group.bench_function(backend, |b| {
+        b.iter(|| black_box(&base).pow_mod(black_box(&exp), black_box(&modulus)))
+    });
+    group.finish();
+}
+
+fn bench_pow_mod_fast_path(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(0x6e31_727a_6b70);
+    let (base, exp, modulus) = inputs(&mut rng, 2048);
+    // The same real parts with every indeterminate coefficient zeroed.
+    let real_only = |n: &NeutrosophicNumber| NeutrosophicNumber::new(n.a.clone(), 0.into());
+    let (base, exp, modulus) = (real_only(&base), real_only(&exp), real_only(&modulus));
+    let mut group = c.benchmark_group("pow_mod_fast_path_2048");
+    group.throughput(Throughput::Elements(1));
+    group.sample_size(10);
+    // The general formula, which exponentiates the sum projection as well.
+    group.bench_function("general", |b| {
+        b.iter(|| {
+            let (base, exp, modulus) = (black_box(&base), black_box(&exp), black_box(&modulus));
+            let term1 = base.a.modpow(&exp.a, &modulus.a);
+            let term2 = (&base.a + &base.b).modpow(&(&exp.a + &exp.b), &(&modulus.a + &modulus.b));
+            NeutrosophicNumber::new(term1.clone(), term2 - term1)
+        })
+    });
+    group.bench_function("dispatch", |b| {
+        b.iter(|| black_box(&base).pow_mod(black_box(&exp), black_box(&modulus)))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_pow_mod,
+    bench_pow_mod_windowed,
+    bench_pow_mod_backend,
+    bench_pow_mod_fast_path
+);
+criterion_main!(benches);
@@ -0,0 +1,31 @@
+//! Peggy's side of the protocol over TCP; see the `tcp_verifier` example.
+//!
+//! Peggy receives `g` and `p`, generates a fresh secret key `x`, sends her public
+//! key `b = g^x mod p`, and answers Victor's challenge `c` with `r = c^x mod p`.
+
+use n1rzkp::wire::{read_number, write_number};
+use n1rzkp::{Prover, SecretKey};
+use std::io;
+use std::net::TcpStream;
+
+/// Bit size of each component of Peggy's secret `x`.
+const BITS: usize = 256;
+
+fn main() -> io::Result<()> {
+    let addr = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "127.0.0.1:7878".to_string());
+    let mut stream = TcpStream::connect(&addr)?;
+    println!("Peggy connected to {}", addr);
+
+    let g = read_number(&mut stream)?;
+    let p = read_number(&mut stream)?;
+    let key = SecretKey::generate(&mut rand::thread_rng(), BITS);
+    write_number(&mut stream, &key.public_key(&g, &p).0)?;
+
+    let prover = Prover::new(key);
+    let c = read_number(&mut stream)?;
+    write_number(&mut stream, &prover.respond(&c, &p))?;
+    println!("Response sent; Victor decides whether to accept.");
+    Ok(())
+}
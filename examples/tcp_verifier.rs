@@ -0,0 +1,56 @@
+//! Victor's side of the protocol over TCP.
+//!
+//! Run `cargo run --example tcp_verifier` in one terminal and
+//! `cargo run --example tcp_prover` in another. Both take an optional address
+//! argument, `127.0.0.1:7878` by default.
+//!
+//! Victor publishes `g` and `p`, receives Peggy's public key `b`, sends the
+//! challenge `c = g^y mod p`, and accepts if her response equals `b^y mod p`.
+//! Every message is one `n1rzkp::wire` frame.
+
+use n1rzkp::neutrosophic_numbers::{generate_neutrosophic_prime, generate_random_neutrosophic};
+use n1rzkp::wire::{read_number, write_number};
+use n1rzkp::{PublicKey, Verifier};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::io;
+use std::net::TcpListener;
+
+/// Bit size of the public parameters `g` and `p`.
+const BITS: usize = 256;
+
+/// Number of Miller-Rabin rounds used to generate `p`.
+const PRIMALITY_ROUNDS: u32 = 20;
+
+fn main() -> io::Result<()> {
+    let addr = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "127.0.0.1:7878".to_string());
+    let listener = TcpListener::bind(&addr)?;
+    println!("Victor listening on {}", listener.local_addr()?);
+
+    let mut rng = StdRng::from_entropy();
+    let p = generate_neutrosophic_prime(&mut rng, BITS, PRIMALITY_ROUNDS);
+    let g = generate_random_neutrosophic(&mut rng, BITS);
+
+    let (mut stream, peer) = listener.accept()?;
+    println!("Peggy connected from {}", peer);
+
+    write_number(&mut stream, &g)?;
+    write_number(&mut stream, &p)?;
+    let b = read_number(&mut stream)?;
+    println!("  b (g^x mod p): {:.50}", b);
+
+    let verifier = Verifier::new(g, p, PublicKey(b));
+    let (c, y) = verifier.challenge(&mut rng);
+    write_number(&mut stream, &c)?;
+    let r = read_number(&mut stream)?;
+    println!("  r (c^x mod p): {:.50}", r);
+
+    if verifier.verify(y.expose(), &r) {
+        println!("ACCEPT: Peggy proved knowledge of 'x'.");
+    } else {
+        println!("REJECT: Peggy's response does not match.");
+    }
+    Ok(())
+}
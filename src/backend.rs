@@ -0,0 +1,195 @@
+//! The integer backend behind `pow_mod` and the `+` and `*` operators.
+//!
+//! By default this is `num-bigint` itself. With the `rug` feature, the operands are
+//! converted to GMP-backed `rug::Integer`s, the operation runs there, and the result
+//! is converted back. The public API keeps using `BigInt` either way, since the
+//! components of `NeutrosophicNumber` are public `BigInt` fields; only the
+//! arithmetic dispatches on the `Int` alias.
+
+use num_bigint::BigInt;
+
+/// The integer type that performs the arithmetic under the active backend.
+#[cfg(not(feature = "rug"))]
+pub(crate) type Int = BigInt;
+
+/// The integer type that performs the arithmetic under the active backend.
+#[cfg(feature = "rug")]
+pub(crate) type Int = rug::Integer;
+
+/// Computes `a + b`.
+#[cfg(not(feature = "rug"))]
+pub(crate) fn add(a: &Int, b: &Int) -> Int {
+    a + b
+}
+
+/// Computes `a + b`.
+#[cfg(feature = "rug")]
+pub(crate) fn add(a: &BigInt, b: &BigInt) -> BigInt {
+    from_int(&Int::from(&to_int(a) + &to_int(b)))
+}
+
+/// Computes `a * b`.
+#[cfg(not(feature = "rug"))]
+pub(crate) fn mul(a: &Int, b: &Int) -> Int {
+    a * b
+}
+
+/// Computes `a * b`.
+#[cfg(feature = "rug")]
+pub(crate) fn mul(a: &BigInt, b: &BigInt) -> BigInt {
+    from_int(&Int::from(&to_int(a) * &to_int(b)))
+}
+
+/// Computes `base^exp mod modulus` with the same results and panics as `BigInt::modpow`.
+///
+/// # Panics
+/// Panics if `exp` is negative or `modulus` is zero.
+#[cfg(not(feature = "rug"))]
+pub(crate) fn modpow(base: &Int, exp: &Int, modulus: &Int) -> Int {
+    base.modpow(exp, modulus)
+}
+
+/// Computes `base^exp mod modulus` with the same results and panics as `BigInt::modpow`.
+///
+/// # Panics
+/// Panics if `exp` is negative or `modulus` is zero.
+#[cfg(feature = "rug")]
+pub(crate) fn modpow(base: &BigInt, exp: &BigInt, modulus: &BigInt) -> BigInt {
+    use num_bigint::Sign;
+
+    assert!(
+        exp.sign() != Sign::Minus,
+        "negative exponentiation is not supported!"
+    );
+    assert!(
+        modulus.sign() != Sign::NoSign,
+        "attempt to calculate with zero modulus!"
+    );
+
+    let abs_modulus = to_int(modulus).abs();
+    let result = to_int(base)
+        .pow_mod(&to_int(exp), &abs_modulus)
+        .expect("a non-negative exponent always has a result");
+    // `BigInt::modpow` follows the sign of the modulus: a negative modulus gives a
+    // result in `(modulus, 0]`, while GMP always returns `[0, |modulus|)`.
+    if modulus.sign() == Sign::Minus && result != 0 {
+        from_int(&(result - abs_modulus))
+    } else {
+        from_int(&result)
+    }
+}
+
+/// Converts a `BigInt` into the backend integer type.
+#[cfg(feature = "rug")]
+fn to_int(n: &BigInt) -> Int {
+    use num_bigint::Sign;
+    use rug::integer::Order;
+
+    let (sign, digits) = n.to_u32_digits();
+    let magnitude = Int::from_digits(&digits, Order::Lsf);
+    if sign == Sign::Minus {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// Converts a backend integer back into a `BigInt`.
+#[cfg(feature = "rug")]
+fn from_int(n: &Int) -> BigInt {
+    use num_bigint::Sign;
+    use rug::integer::Order;
+
+    let sign = match n.cmp0() {
+        core::cmp::Ordering::Less => Sign::Minus,
+        core::cmp::Ordering::Equal => Sign::NoSign,
+        core::cmp::Ordering::Greater => Sign::Plus,
+    };
+    BigInt::new(sign, n.to_digits::<u32>(Order::Lsf))
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use num_bigint::RandBigInt;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn test_backend_add_mul() {
+        let cases = [
+            (3, 4, 7, 12),
+            (-3, 4, 1, -12),
+            (-3, -4, -7, 12),
+            (0, 5, 5, 0),
+        ];
+        for (a, b, sum, product) in cases {
+            let (a, b) = (BigInt::from(a), BigInt::from(b));
+            assert_eq!(add(&a, &b), BigInt::from(sum));
+            assert_eq!(mul(&a, &b), BigInt::from(product));
+        }
+
+        // Operands wider than a machine word: (2^127 - 1)^2 = 2^254 - 2^128 + 1.
+        let m: BigInt = (BigInt::from(1) << 127u32) - 1;
+        let square = (BigInt::from(1) << 254u32) - (BigInt::from(1) << 128u32) + 1;
+        assert_eq!(mul(&m, &m), square);
+        assert_eq!(add(&m, &-&m), BigInt::from(0));
+    }
+
+    /// Square-and-multiply over `mod_floor`, independent of any backend's `modpow`.
+    fn reference_modpow(base: &BigInt, exp: &BigInt, modulus: &BigInt) -> BigInt {
+        use num_integer::Integer;
+        use num_traits::{One, Zero};
+
+        let mut result = BigInt::one().mod_floor(modulus);
+        let mut square = base.mod_floor(modulus);
+        let mut exp = exp.clone();
+        while !exp.is_zero() {
+            if exp.is_odd() {
+                result = (&result * &square).mod_floor(modulus);
+            }
+            square = (&square * &square).mod_floor(modulus);
+            exp >>= 1;
+        }
+        result
+    }
+
+    #[test]
+    fn test_backend_modpow_matches_reference() {
+        let cases = [
+            (2, 3, 5, 3),
+            (3, 3, 7, 6),
+            (5, 4, 11, 9),
+            (-3, 3, 7, 1),
+            (3, 0, 7, 1),
+            (4, 5, 1, 0),
+            (3, 3, -7, -1),
+        ];
+        for (base, exp, modulus, expected) in cases {
+            let (base, exp, modulus) = (base.into(), exp.into(), modulus.into());
+            assert_eq!(modpow(&base, &exp, &modulus), BigInt::from(expected));
+            assert_eq!(
+                reference_modpow(&base, &exp, &modulus),
+                BigInt::from(expected)
+            );
+        }
+
+        // Fermat's little theorem for the Mersenne prime 2^127 - 1.
+        let prime: BigInt = (BigInt::from(1) << 127u32) - 1;
+        let order = &prime - 1;
+        for base in [2, 3, 65537] {
+            assert_eq!(modpow(&base.into(), &order, &prime), BigInt::from(1));
+        }
+
+        let mut rng = StdRng::seed_from_u64(132);
+        for bits in [64, 256, 1024] {
+            let base = rng.gen_bigint(bits);
+            let exp = rng.gen_biguint(bits).into();
+            let modulus = rng.gen_bigint(bits);
+            assert_eq!(
+                modpow(&base, &exp, &modulus),
+                reference_modpow(&base, &exp, &modulus)
+            );
+        }
+    }
+}
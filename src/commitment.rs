@@ -0,0 +1,98 @@
+use crate::neutrosophic_numbers::NeutrosophicNumber;
+
+/// A Pedersen-style commitment `C = g^v * h^r mod p` to a value `v`.
+///
+/// The committer publishes `C` and later reveals `(v, r)`; anyone can then check
+/// the opening with `open`. The generators `g` and `h` must be independent (nobody
+/// may know `log_g h`) for the commitment to be binding.
+///
+/// WARNING: This inherits the unproven group structure of the neutrosophic
+/// modular arithmetic and is intended for experimentation, not production use.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Commitment(pub NeutrosophicNumber);
+
+impl Commitment {
+    /// Commits to `value` using the blinding factor `randomness`.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - The first public generator.
+    /// * `h` - The second public generator.
+    /// * `p` - The public neutrosophic modulus.
+    /// * `value` - The committed value `v`.
+    /// * `randomness` - The secret blinding factor `r`.
+    pub fn commit(
+        g: &NeutrosophicNumber,
+        h: &NeutrosophicNumber,
+        p: &NeutrosophicNumber,
+        value: &NeutrosophicNumber,
+        randomness: &NeutrosophicNumber,
+    ) -> Commitment {
+        let g_v = g.pow_mod(value, p);
+        let h_r = h.pow_mod(randomness, p);
+        Commitment(g_v.mul_mod(&h_r, p))
+    }
+
+    /// Checks that `(value, randomness)` opens this commitment.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - The first public generator.
+    /// * `h` - The second public generator.
+    /// * `p` - The public neutrosophic modulus.
+    /// * `value` - The revealed value `v`.
+    /// * `randomness` - The revealed blinding factor `r`.
+    ///
+    /// # Returns
+    /// `true` if `g^v * h^r` is congruent to the commitment modulo `p`.
+    pub fn open(
+        &self,
+        g: &NeutrosophicNumber,
+        h: &NeutrosophicNumber,
+        p: &NeutrosophicNumber,
+        value: &NeutrosophicNumber,
+        randomness: &NeutrosophicNumber,
+    ) -> bool {
+        let expected = Commitment::commit(g, h, p, value, randomness);
+        self.0.congruent_mod(&expected.0, p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Commitment parameters: p = 101+2I (101 and 103 are prime), g = 3+2I, h = 7+5I.
+    fn commitment_params() -> (NeutrosophicNumber, NeutrosophicNumber, NeutrosophicNumber) {
+        (
+            NeutrosophicNumber::from((3, 2)),
+            NeutrosophicNumber::from((7, 5)),
+            NeutrosophicNumber::from((101, 2)),
+        )
+    }
+
+    #[test]
+    fn test_commitment_open() {
+        let (g, h, p) = commitment_params();
+        let value = NeutrosophicNumber::from((42, 7));
+        let randomness = NeutrosophicNumber::from((1234, 56));
+        let commitment = Commitment::commit(&g, &h, &p, &value, &randomness);
+        let expected = g
+            .pow_mod(&value, &p)
+            .mul_mod(&h.pow_mod(&randomness, &p), &p);
+        assert_eq!(commitment, Commitment(expected));
+        assert!(commitment.open(&g, &h, &p, &value, &randomness));
+    }
+
+    #[test]
+    fn test_commitment_rejects_mismatched_open() {
+        let (g, h, p) = commitment_params();
+        let value = NeutrosophicNumber::from((42, 7));
+        let randomness = NeutrosophicNumber::from((1234, 56));
+        let commitment = Commitment::commit(&g, &h, &p, &value, &randomness);
+        let wrong_value = NeutrosophicNumber::from((43, 7));
+        let wrong_randomness = NeutrosophicNumber::from((1234, 57));
+        assert!(!commitment.open(&g, &h, &p, &wrong_value, &randomness));
+        assert!(!commitment.open(&g, &h, &p, &value, &wrong_randomness));
+    }
+}
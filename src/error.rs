@@ -0,0 +1,123 @@
+use alloc::string::String;
+use core::error::Error;
+use core::fmt;
+
+/// The error type shared by the crate's checked arithmetic, inversion, and parsing.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum NeutrosophicError {
+    /// The real part `p1` of the modulus is zero or negative.
+    NonPositiveModulus,
+    /// The modulus sum `p1 + p2` is zero.
+    ZeroModulusSum,
+    /// An exponent component is negative.
+    NegativeExponent,
+    /// A protocol parameter lies outside the range reduced modulo `p`.
+    OutOfRange(String),
+    /// A component is not coprime to its modulus, so no inverse exists.
+    NotInvertible,
+    /// The input could not be parsed as a neutrosophic number.
+    ParseError(String),
+    /// A required environment variable, named by the payload, is not set.
+    MissingEnvVar(String),
+}
+
+impl fmt::Display for NeutrosophicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NeutrosophicError::NonPositiveModulus => {
+                write!(f, "neutrosophic modulus p1 and sum p1+p2 must be positive")
+            }
+            NeutrosophicError::ZeroModulusSum => {
+                write!(f, "neutrosophic modulus sum p1+p2 must be nonzero")
+            }
+            NeutrosophicError::NegativeExponent => {
+                write!(f, "neutrosophic exponent components must be non-negative")
+            }
+            NeutrosophicError::OutOfRange(reason) => {
+                write!(f, "neutrosophic parameter out of range: {}", reason)
+            }
+            NeutrosophicError::NotInvertible => {
+                write!(f, "neutrosophic number is not invertible modulo p")
+            }
+            NeutrosophicError::ParseError(reason) => {
+                write!(f, "invalid neutrosophic number: {}", reason)
+            }
+            NeutrosophicError::MissingEnvVar(name) => {
+                write!(f, "environment variable {} is not set", name)
+            }
+        }
+    }
+}
+
+impl Error for NeutrosophicError {}
+
+/// The reason `Verifier::verify_detailed` rejected a response.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum VerifyFailure {
+    /// The public parameters `g`, `p`, `b` failed `validate_params`.
+    InvalidParams(NeutrosophicError),
+    /// The response is not reduced modulo `p`, so it cannot be an honest `c^x mod p`.
+    ResponseOutOfRange,
+    /// The response is well-formed but does not equal `b^y mod p`.
+    Mismatch,
+}
+
+impl fmt::Display for VerifyFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyFailure::InvalidParams(err) => write!(f, "invalid protocol parameters: {}", err),
+            VerifyFailure::ResponseOutOfRange => {
+                write!(f, "response is not reduced modulo p")
+            }
+            VerifyFailure::Mismatch => write!(f, "response does not match b^y mod p"),
+        }
+    }
+}
+
+impl Error for VerifyFailure {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            VerifyFailure::InvalidParams(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neutrosophic_error_display() {
+        let variants = [
+            NeutrosophicError::NonPositiveModulus,
+            NeutrosophicError::ZeroModulusSum,
+            NeutrosophicError::NegativeExponent,
+            NeutrosophicError::OutOfRange("g".to_string()),
+            NeutrosophicError::NotInvertible,
+            NeutrosophicError::ParseError("unexpected token".to_string()),
+            NeutrosophicError::MissingEnvVar("N1RZKP_G".to_string()),
+        ];
+        for variant in variants {
+            assert!(!variant.to_string().is_empty(), "{:?}", variant);
+        }
+    }
+
+    #[test]
+    fn test_neutrosophic_error_is_send_sync() {
+        fn assert_send_sync<T: Send + Sync + std::error::Error>() {}
+        assert_send_sync::<NeutrosophicError>();
+    }
+
+    #[test]
+    fn test_verify_failure_display_and_source() {
+        let invalid = VerifyFailure::InvalidParams(NeutrosophicError::NonPositiveModulus);
+        assert_eq!(
+            invalid.to_string(),
+            "invalid protocol parameters: neutrosophic modulus p1 and sum p1+p2 must be positive"
+        );
+        assert!(invalid.source().is_some());
+        assert!(VerifyFailure::Mismatch.source().is_none());
+        assert!(!VerifyFailure::ResponseOutOfRange.to_string().is_empty());
+    }
+}
@@ -0,0 +1,246 @@
+use crate::neutrosophic_numbers::{MAX_BIT_SIZE, NeutrosophicNumber, generate_random_neutrosophic};
+use crate::protocol::GroupParams;
+use crate::secret::Secret;
+use num_bigint::{BigInt, RandBigInt, Sign};
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+/// Peggy's secret key `x`.
+///
+/// The key is held in a `Secret`, so it is wiped on drop and redacted in `Debug`
+/// output. It deliberately has no conversion into a `PublicKey` or back into a bare
+/// number; the only way from one to the other is `public_key`:
+///
+/// ```compile_fail
+/// use n1rzkp::keys::{PublicKey, SecretKey};
+/// let key = SecretKey::generate(&mut rand::thread_rng(), 64);
+/// let public: PublicKey = key.into();
+/// ```
+#[derive(Debug)]
+pub struct SecretKey(Secret);
+
+impl SecretKey {
+    /// Takes ownership of a secret key value.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The secret neutrosophic number.
+    pub fn new(x: NeutrosophicNumber) -> Self {
+        SecretKey(Secret::new(x))
+    }
+
+    /// Generates a random secret key with components of the given bit size.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - A mutable reference to a random number generator.
+    /// * `bits` - The bit size of each component of `x`.
+    pub fn generate<R: Rng + RandBigInt>(rng: &mut R, bits: usize) -> Self {
+        SecretKey::new(generate_random_neutrosophic(rng, bits))
+    }
+
+    /// Derives the matching public key `b = g^x mod p`.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - The public generator of the group.
+    /// * `p` - The public neutrosophic modulus.
+    pub fn public_key(&self, g: &NeutrosophicNumber, p: &NeutrosophicNumber) -> PublicKey {
+        PublicKey(g.pow_mod(self.expose(), p))
+    }
+
+    /// Derives the matching public key `b = g^x mod p` in a validated group.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The generator `g` and modulus `p`.
+    pub fn public_key_for(&self, group: &GroupParams) -> PublicKey {
+        self.public_key(group.g(), group.p())
+    }
+
+    /// Rerandomizes the keypair by a blinding exponent `r`.
+    ///
+    /// The blinded public key is `b' = b * g^r mod p`, which equals `g^(x+r) mod p`
+    /// because `pow_mod` adds exponents within each projection. The blinded secret
+    /// `x + r` is a valid secret key for `b'`.
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - The public generator of the group.
+    /// * `p` - The public neutrosophic modulus.
+    /// * `r` - The blinding exponent, with non-negative components.
+    ///
+    /// # Returns
+    /// The pair `(b', x + r)`.
+    pub fn blind(
+        &self,
+        g: &NeutrosophicNumber,
+        p: &NeutrosophicNumber,
+        r: &NeutrosophicNumber,
+    ) -> (PublicKey, NeutrosophicNumber) {
+        let blinded_public = self.public_key(g, p).0.mul_mod(&g.pow_mod(r, p), p);
+        let blinded_secret = self.expose() + r;
+        (PublicKey(blinded_public), blinded_secret)
+    }
+
+    /// Borrows the secret value for use in a computation.
+    pub fn expose(&self) -> &NeutrosophicNumber {
+        self.0.expose()
+    }
+
+    /// Consumes the key and returns the `Secret` holding `x`.
+    pub fn into_secret(self) -> Secret {
+        self.0
+    }
+}
+
+/// Peggy's public key `b = g^x mod p`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PublicKey(pub NeutrosophicNumber);
+
+/// Lazily generates fresh keypairs `(x, g^x mod p)`.
+///
+/// Each call to `next` draws a new `bits`-bit secret key from `rng` and derives its
+/// public key, so arbitrarily many pairs can be consumed without collecting them.
+/// The iterator never ends; bound it with `take`.
+///
+/// # Arguments
+///
+/// * `rng` - A mutable reference to a random number generator.
+/// * `g` - The public generator of the group.
+/// * `p` - The public neutrosophic modulus.
+/// * `bits` - The bit size of each component of `x`.
+pub fn keypair_iter<R: Rng + RandBigInt>(
+    rng: &mut R,
+    g: &NeutrosophicNumber,
+    p: &NeutrosophicNumber,
+    bits: usize,
+) -> impl Iterator<Item = (SecretKey, PublicKey)> {
+    std::iter::repeat_with(move || {
+        let key = SecretKey::generate(rng, bits);
+        let public_key = key.public_key(g, p);
+        (key, public_key)
+    })
+}
+
+/// Salt mixed into every hash of `secret_from_passphrase`.
+const PASSPHRASE_SALT: &[u8] = b"n1rzkp/passphrase/v1";
+
+/// Deterministically derives a secret key from a passphrase.
+///
+/// Each component is SHA-256 in counter mode: the concatenated digests of the salt,
+/// the component index, a 32-bit big-endian block counter, and the passphrase, read
+/// as an unsigned big-endian integer and truncated to `bits` bits. The top bit is
+/// then set, as in `generate_random_neutrosophic_exact`, so both components are
+/// positive and exactly `bits` bits long.
+///
+/// WARNING: The salt is fixed and SHA-256 is fast, so a low-entropy passphrase gives
+/// a guessable key. This is meant for reproducible test setups.
+///
+/// # Arguments
+///
+/// * `passphrase` - The passphrase the key is derived from.
+/// * `bits` - The bit size of each component of `x`, in `1..=MAX_BIT_SIZE`.
+///
+/// # Panics
+/// Panics if `bits` is zero or exceeds `MAX_BIT_SIZE`.
+pub fn secret_from_passphrase(passphrase: &str, bits: usize) -> SecretKey {
+    assert!(bits >= 1, "derived components need at least 1 bit");
+    assert!(
+        bits <= MAX_BIT_SIZE,
+        "derived components are limited to MAX_BIT_SIZE bits"
+    );
+    let component = |index: u8| -> BigInt {
+        let blocks = bits.div_ceil(256) as u32;
+        let mut bytes = Vec::with_capacity(blocks as usize * 32);
+        for counter in 0..blocks {
+            let mut hasher = Sha256::new();
+            hasher.update(PASSPHRASE_SALT);
+            hasher.update([index]);
+            hasher.update(counter.to_be_bytes());
+            hasher.update(passphrase.as_bytes());
+            bytes.extend_from_slice(&hasher.finalize());
+        }
+        let excess = blocks as usize * 256 - bits;
+        let value = BigInt::from_bytes_be(Sign::Plus, &bytes) >> excess;
+        value | (BigInt::from(1) << (bits - 1))
+    };
+    SecretKey::new(NeutrosophicNumber::new(component(0), component(1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn test_public_key_matches_pow_mod() {
+        let g = NeutrosophicNumber::from((3, 2));
+        let p = NeutrosophicNumber::from((7, 4));
+        let key = SecretKey::generate(&mut StdRng::seed_from_u64(83), 64);
+        assert_eq!(
+            key.public_key(&g, &p),
+            PublicKey(g.pow_mod(key.expose(), &p))
+        );
+        assert_eq!(format!("{:?}", key), "SecretKey(Secret(..))");
+    }
+
+    #[test]
+    fn test_keypair_iter_yields_distinct_valid_pairs() {
+        use std::collections::HashSet;
+
+        let g = NeutrosophicNumber::from((3, 2));
+        let p = NeutrosophicNumber::from((101, 2));
+        let mut rng = StdRng::seed_from_u64(103);
+        let pairs: Vec<_> = keypair_iter(&mut rng, &g, &p, 64).take(5).collect();
+        assert_eq!(pairs.len(), 5);
+
+        let secrets: HashSet<NeutrosophicNumber> =
+            pairs.iter().map(|(key, _)| key.expose().clone()).collect();
+        assert_eq!(secrets.len(), 5);
+        for (key, public_key) in &pairs {
+            assert_eq!(public_key.0, g.pow_mod(key.expose(), &p));
+        }
+    }
+
+    #[test]
+    fn test_blind_matches_pow_mod_and_verifies() {
+        use crate::protocol::{Prover, Verifier, run_rounds};
+
+        let g = NeutrosophicNumber::from((3, 2));
+        let p = NeutrosophicNumber::from((101, 2));
+        let mut rng = StdRng::seed_from_u64(115);
+        let key = SecretKey::generate(&mut rng, 64);
+        let r = generate_random_neutrosophic(&mut rng, 64);
+
+        let (blinded_public, blinded_secret) = key.blind(&g, &p, &r);
+        assert_eq!(blinded_secret, key.expose() + &r);
+        assert_eq!(blinded_public.0, g.pow_mod(&blinded_secret, &p));
+
+        let verifier = Verifier::new(g, p, blinded_public);
+        let prover = Prover::new(SecretKey::new(blinded_secret));
+        assert!(run_rounds(&prover, &verifier, 3, &mut rng));
+    }
+
+    #[test]
+    fn test_secret_from_passphrase_is_deterministic() {
+        let key = secret_from_passphrase("correct horse battery staple", 300);
+        let again = secret_from_passphrase("correct horse battery staple", 300);
+        assert_eq!(key.expose(), again.expose());
+        assert_eq!(key.expose().a.bits(), 300);
+        assert_eq!(key.expose().b.bits(), 300);
+        assert_ne!(key.expose().a, key.expose().b);
+
+        let other = secret_from_passphrase("correct horse battery stapler", 300);
+        assert_ne!(key.expose(), other.expose());
+        assert_ne!(
+            secret_from_passphrase("", 64).expose(),
+            secret_from_passphrase(" ", 64).expose()
+        );
+        assert_eq!(
+            secret_from_passphrase("x", 1).expose(),
+            &NeutrosophicNumber::from((1, 1))
+        );
+    }
+}
@@ -0,0 +1,68 @@
+//! Neutrosophic numbers and the Neutrosophic 1-Round ZKP protocol.
+//!
+//! The arithmetic lives in `neutrosophic_numbers`, the shared error type in
+//! `error`, the `Secret` wrapper for key material in `secret`, and the typed
+//! `SecretKey` and `PublicKey` in `keys`. `commitment` builds Pedersen-style
+//! commitments on top of the arithmetic, `multi_neutrosophic` generalizes it to
+//! several indeterminacies, and `protocol` implements the interaction between
+//! Peggy (the prover) and Victor (the verifier). `wire` frames numbers for
+//! sending them over a byte stream, as in the `tcp_verifier` and `tcp_prover`
+//! examples.
+//! With the `wasm` feature, `wasm` exposes the demo to JavaScript.
+//!
+//! Without the default `std` feature, the crate is `no_std` (it still needs
+//! `alloc`) and consists of `neutrosophic_numbers` minus the random generators,
+//! `multi_neutrosophic`, `commitment`, `error`, and `secret`.
+//!
+//! A minimal end-to-end run with small parameters:
+//!
+//! ```
+//! # #[cfg(feature = "std")] {
+//! use n1rzkp::{NeutrosophicNumber, Prover, Secret, Verifier, run_rounds};
+//! use rand::SeedableRng;
+//! use rand::rngs::StdRng;
+//!
+//! // p = 7+4I (7 and 11 are prime), g = 3+2I, and Peggy's secret x = 4+1I.
+//! let g = NeutrosophicNumber::from((3, 2));
+//! let p = NeutrosophicNumber::from((7, 4));
+//! let x = NeutrosophicNumber::from((4, 1));
+//! let b = g.pow_mod(&x, &p);
+//!
+//! let prover = Prover { x: Secret::new(x) };
+//! let verifier = Verifier { g, p, b };
+//! let mut rng = StdRng::seed_from_u64(7);
+//! assert!(run_rounds(&prover, &verifier, 5, &mut rng));
+//! # }
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod backend;
+pub mod commitment;
+pub mod error;
+#[cfg(feature = "std")]
+pub mod keys;
+pub mod multi_neutrosophic;
+pub mod neutrosophic_numbers;
+#[cfg(feature = "std")]
+pub mod protocol;
+pub mod secret;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "std")]
+pub mod wire;
+
+pub use error::{NeutrosophicError, VerifyFailure};
+#[cfg(feature = "std")]
+pub use keys::{PublicKey, SecretKey, keypair_iter, secret_from_passphrase};
+pub use neutrosophic_numbers::NeutrosophicNumber;
+#[cfg(feature = "std")]
+pub use protocol::{
+    AggregateProof, GroupParams, NeutrosophicProof, ProtocolParams, Prover, Transcript, Verifier,
+    challenge_from_seed, neutrosophic_one_round_zkp_protocol, params_from_env, prove_conjunction,
+    prove_noninteractive, run_rounds, run_with_transcript, soundness_error, validate_params,
+    verify_conjunction, verify_noninteractive,
+};
+pub use secret::Secret;
@@ -1,59 +1,86 @@
-// Import the necessary definitions from the neutrosophic_numbers module.
-mod neutrosophic_numbers;
-use neutrosophic_numbers::{NeutrosophicNumber, generate_random_neutrosophic};
-
-/// Simulates the Neutrosophic 1-Round ZKP protocol interaction.
-///
-/// This function executes the core logic of the ZKP, where Peggy (the prover)
-/// attempts to prove knowledge of the secret `x` to Victor (the verifier).
-///
-/// # Arguments
-/// * `g` - The public generator of the group.
-/// * `p` - The public neutrosophic modulus (prime).
-/// * `b` - The public value `g^x mod p`.
-/// * `x` - Peggy's secret key.
-///
-/// # Returns
-/// `true` if the verification succeeds, `false` otherwise.
-fn neutrosophic_one_round_zkp_protocol(
-    g: &NeutrosophicNumber,
-    p: &NeutrosophicNumber,
-    b: &NeutrosophicNumber,
-    x: &NeutrosophicNumber,
-) -> bool {
-    let mut rng = rand::thread_rng();
-
-    // Step 1 (Victor): Generate a random secret `y`.
-    // In a real scenario, the bit size should match the security level.
-    let y = generate_random_neutrosophic(&mut rng, 2048);
-
-    // Step 2 (Victor): Compute the challenge `c = g^y mod p` and send it to Peggy.
-    let c = g.pow_mod(&y, p);
-
-    // Step 3 (Peggy): Compute the response `r = c^x mod p` using her secret `x`.
-    let r_peggy = c.pow_mod(x, p);
-
-    // Step 4 (Victor): Compute the verification value `r' = b^y mod p` using his secret `y`.
-    let r_victor = b.pow_mod(&y, p);
+// Import the necessary definitions from the library crate.
+use clap::Parser;
+use n1rzkp::keys::SecretKey;
+use n1rzkp::neutrosophic_numbers::{
+    MAX_BIT_SIZE, generate_neutrosophic_prime, generate_random_neutrosophic,
+};
+use n1rzkp::protocol::{ENV_G, ENV_P, Prover, Verifier, params_from_env, run_rounds};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::env;
+
+/// Number of Miller-Rabin rounds used when `--prime` is set.
+const PRIMALITY_ROUNDS: u32 = 20;
+
+/// Runs the Neutrosophic 1-Round ZKP protocol with an honest and a dishonest Peggy.
+#[derive(Parser, Debug)]
+#[command(version, about)]
+struct Args {
+    /// Bit size of the parameters p, g and x (8 to 65536).
+    #[arg(long, default_value_t = 2048, value_parser = parse_bits)]
+    bits: usize,
+
+    /// Number of challenge/response rounds per run.
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+    rounds: u64,
+
+    /// Seed for the random number generator, for reproducible runs.
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Generate a neutrosophic prime modulus instead of a random one.
+    #[arg(long)]
+    prime: bool,
+}
 
-    // Victor checks if Peggy's response matches his verification value.
-    r_peggy == r_victor
+/// Parses `--bits`, rejecting sizes below 8 or above `MAX_BIT_SIZE`.
+fn parse_bits(s: &str) -> Result<usize, String> {
+    let bits: usize = s.parse().map_err(|e| format!("{e}"))?;
+    if !(8..=MAX_BIT_SIZE).contains(&bits) {
+        return Err(format!(
+            "bit size must be between 8 and {MAX_BIT_SIZE}, got {bits}"
+        ));
+    }
+    Ok(bits)
 }
 
 fn main() {
-    println!("Starting the Neutrosophic 1-Round ZKP protocol test with 2048-bit numbers...");
+    let args = Args::parse();
+    let bit_length_params = args.bits; // The bit size for p, g, x.
+    println!(
+        "Starting the Neutrosophic 1-Round ZKP protocol test with {}-bit numbers...",
+        bit_length_params
+    );
 
-    let mut rng = rand::thread_rng();
-    let bit_length_params = 2048; // Define the bit size for p, g, x.
+    let mut rng = match args.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
 
     // --- Parameter Setup ---
     // WARNING: This is a simplified setup for algebraic demonstration only.
     // In a real cryptographic system, `p` must be a large prime (or have a specific
     // structure), and `g` must be a generator of the group modulo `p`.
-    // The concept of a "neutrosophic prime" is still theoretical and not enforced here.
-    let p = generate_random_neutrosophic(&mut rng, bit_length_params);
-    let g = generate_random_neutrosophic(&mut rng, bit_length_params);
-    let x_secret = generate_random_neutrosophic(&mut rng, bit_length_params);
+    // The concept of a "neutrosophic prime" is still theoretical; `--prime` only makes
+    // both projections `p1` and `p1+p2` probable primes.
+    // Setting N1RZKP_G or N1RZKP_P replaces the generated `g` and `p` entirely.
+    let (g, p) = if env::var_os(ENV_G).is_some() || env::var_os(ENV_P).is_some() {
+        match params_from_env() {
+            Ok(group) => (group.g().clone(), group.p().clone()),
+            Err(err) => {
+                eprintln!("Error: invalid {ENV_G}/{ENV_P} parameters: {err}");
+                return;
+            }
+        }
+    } else {
+        let p = if args.prime {
+            generate_neutrosophic_prime(&mut rng, bit_length_params, PRIMALITY_ROUNDS)
+        } else {
+            generate_random_neutrosophic(&mut rng, bit_length_params)
+        };
+        (generate_random_neutrosophic(&mut rng, bit_length_params), p)
+    };
+    let x_secret = SecretKey::generate(&mut rng, bit_length_params);
 
     // Ensure the generated parameters are "positive" as per the neutrosophic definition.
     if !p.is_positive() || !g.is_positive() {
@@ -64,31 +91,23 @@ fn main() {
     }
 
     // Peggy computes her public key `b = g^x mod p`.
-    let b = g.pow_mod(&x_secret, &p);
+    let b = x_secret.public_key(&g, &p);
 
     println!(
-        "\nProtocol Parameters ({} bits, truncated for display):",
-        bit_length_params
-    );
-    println!(
-        "  g (generator): {}...",
-        g.a.to_string().chars().take(50).collect::<String>()
-    );
-    println!(
-        "  p (modulus):   {}...",
-        p.a.to_string().chars().take(50).collect::<String>()
-    );
-    println!(
-        "  b (g^x mod p): {}...",
-        b.a.to_string().chars().take(50).collect::<String>()
-    );
-    println!(
-        "  x (Peggy's secret): {}...",
-        x_secret.a.to_string().chars().take(50).collect::<String>()
+        "\nProtocol Parameters ({} bits, {} round(s), truncated for display):",
+        bit_length_params, args.rounds
     );
+    println!("  g (generator): {:.50}", g);
+    println!("  p (modulus):   {:.50}", p);
+    println!("  b (g^x mod p): {:.50}", b.0);
+    println!("  x (Peggy's secret): {:.50}", x_secret.expose());
+
+    let verifier = Verifier::new(g, p, b);
+    let rounds = args.rounds as usize;
 
     println!("\n--- Test 1: Peggy KNOWS the secret key 'x' ---");
-    let result_known_x = neutrosophic_one_round_zkp_protocol(&g, &p, &b, &x_secret);
+    let honest = Prover::new(x_secret);
+    let result_known_x = run_rounds(&honest, &verifier, rounds, &mut rng);
     if result_known_x {
         println!("Verification SUCCESSFUL! Peggy proved knowledge of 'x' without revealing it.");
     } else {
@@ -98,11 +117,9 @@ fn main() {
     println!("\n--- Test 2: Peggy does NOT KNOW the secret key 'x' ---");
     // Generate a fake secret for a dishonest Peggy.
     let x_fake = generate_random_neutrosophic(&mut rng, bit_length_params);
-    println!(
-        "  Fake x (from Peggy): {}...",
-        x_fake.a.to_string().chars().take(50).collect::<String>()
-    );
-    let result_fake_x = neutrosophic_one_round_zkp_protocol(&g, &p, &b, &x_fake);
+    println!("  Fake x (from Peggy): {:.50}", x_fake);
+    let dishonest = Prover::new(SecretKey::new(x_fake));
+    let result_fake_x = run_rounds(&dishonest, &verifier, rounds, &mut rng);
     if result_fake_x {
         println!(
             "Verification SUCCEEDED (INCORRECT)! The protocol logic is flawed, as Peggy should not have passed."
@@ -113,40 +130,3 @@ fn main() {
         );
     }
 }
-
-// Unit tests for the neutrosophic number operations.
-#[cfg(test)]
-mod tests {
-    use super::neutrosophic_numbers::*;
-    use num_bigint::ToBigInt;
-
-    #[test]
-    fn test_neutrosophic_addition() {
-        let n1 = NeutrosophicNumber::new(1.to_bigint().unwrap(), 2.to_bigint().unwrap());
-        let n2 = NeutrosophicNumber::new(3.to_bigint().unwrap(), 4.to_bigint().unwrap());
-        let expected = NeutrosophicNumber::new(4.to_bigint().unwrap(), 6.to_bigint().unwrap());
-        assert_eq!(n1 + n2, expected);
-    }
-
-    #[test]
-    fn test_neutrosophic_multiplication() {
-        // Based on I^2 = I
-        let n1 = NeutrosophicNumber::new(1.to_bigint().unwrap(), 2.to_bigint().unwrap());
-        let n2 = NeutrosophicNumber::new(3.to_bigint().unwrap(), 4.to_bigint().unwrap());
-        // (1+2I)*(3+4I) = 1*3 + 1*4I + 2I*3 + 2I*4I = 3 + 4I + 6I + 8I^2 = 3 + 10I + 8I = 3 + 18I
-        let expected = NeutrosophicNumber::new(3.to_bigint().unwrap(), 18.to_bigint().unwrap());
-        assert_eq!(n1 * n2, expected);
-    }
-
-    #[test]
-    fn test_neutrosophic_pow_mod() {
-        // g = 2+1I, x = 3+0I, p = 5+0I
-        // Real part: 2^3 mod 5 = 8 mod 5 = 3
-        // Indeterminate part: ((2+1)^(3+0) mod (5+0)) - 3 = (3^3 mod 5) - 3 = (27 mod 5) - 3 = 2 - 3 = -1
-        let g = NeutrosophicNumber::new(2.to_bigint().unwrap(), 1.to_bigint().unwrap());
-        let x = NeutrosophicNumber::new(3.to_bigint().unwrap(), 0.to_bigint().unwrap());
-        let p = NeutrosophicNumber::new(5.to_bigint().unwrap(), 0.to_bigint().unwrap());
-        let expected = NeutrosophicNumber::new(3.to_bigint().unwrap(), (-1).to_bigint().unwrap());
-        assert_eq!(g.pow_mod(&x, &p), expected);
-    }
-}
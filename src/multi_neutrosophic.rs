@@ -0,0 +1,136 @@
+use crate::neutrosophic_numbers::NeutrosophicNumber;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Add, Mul};
+use num_bigint::BigInt;
+
+/// A neutrosophic number with several independent indeterminacies,
+/// `a + b_1 I_1 + b_2 I_2 + ... + b_n I_n`.
+///
+/// The indeterminacies follow the orthogonal-idempotent model: `I_k^2 = I_k` and
+/// `I_j I_k = 0` for `j != k`. A `NeutrosophicNumber` `a + bI` is the special case
+/// with a single coefficient, see the `From<NeutrosophicNumber>` impl.
+///
+/// Operands with different numbers of coefficients are combined by treating the
+/// missing coefficients as zero; the result has the longer length.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct MultiNeutrosophic {
+    /// The real part.
+    pub a: BigInt,
+    /// The coefficients `b_k` of the indeterminacies `I_k`, in order.
+    pub coeffs: Vec<BigInt>,
+}
+
+impl MultiNeutrosophic {
+    /// Constructs a new `MultiNeutrosophic`.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The real part.
+    /// * `coeffs` - The coefficients of `I_1, ..., I_n`.
+    pub fn new(a: BigInt, coeffs: Vec<BigInt>) -> Self {
+        MultiNeutrosophic { a, coeffs }
+    }
+
+    /// Returns the number of indeterminacies `n`.
+    pub fn indeterminacies(&self) -> usize {
+        self.coeffs.len()
+    }
+
+    /// Returns the coefficient of `I_k` (zero-based), or zero past the end.
+    fn coeff(&self, k: usize) -> BigInt {
+        self.coeffs.get(k).cloned().unwrap_or_default()
+    }
+}
+
+/// Embeds `a + bI` as `a + b I_1`.
+impl From<NeutrosophicNumber> for MultiNeutrosophic {
+    fn from(n: NeutrosophicNumber) -> Self {
+        let (a, b) = n.into_parts();
+        MultiNeutrosophic::new(a, vec![b])
+    }
+}
+
+/// Implements the addition operator `+` for `MultiNeutrosophic`.
+///
+/// Addition is performed element-wise on the real part and every coefficient.
+impl Add for MultiNeutrosophic {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let n = self.coeffs.len().max(other.coeffs.len());
+        let coeffs = (0..n).map(|k| self.coeff(k) + other.coeff(k)).collect();
+        MultiNeutrosophic::new(self.a + other.a, coeffs)
+    }
+}
+
+/// Implements the multiplication operator `*` for `MultiNeutrosophic`.
+///
+/// With `I_k^2 = I_k` and `I_j I_k = 0` for `j != k`, the cross terms between
+/// different indeterminacies vanish:
+/// `(a + sum b_k I_k) * (c + sum d_k I_k) = ac + sum (a d_k + b_k c + b_k d_k) I_k`.
+impl Mul for MultiNeutrosophic {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let n = self.coeffs.len().max(other.coeffs.len());
+        let coeffs = (0..n)
+            .map(|k| product_coeff(&self.a, &self.coeff(k), &other.a, &other.coeff(k)))
+            .collect();
+        MultiNeutrosophic::new(self.a * other.a, coeffs)
+    }
+}
+
+/// Returns the coefficient `ad + bc + bd` of `I_k` in `(a + bI_k) * (c + dI_k)`.
+fn product_coeff(a: &BigInt, b: &BigInt, c: &BigInt, d: &BigInt) -> BigInt {
+    a * d + b * c + b * d
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn multi(a: i64, coeffs: &[i64]) -> MultiNeutrosophic {
+        MultiNeutrosophic::new(
+            BigInt::from(a),
+            coeffs.iter().copied().map(BigInt::from).collect(),
+        )
+    }
+
+    #[test]
+    fn test_multi_neutrosophic_addition() {
+        let x = multi(1, &[2, 3]);
+        let y = multi(4, &[-5, 6]);
+        assert_eq!(x + y, multi(5, &[-3, 9]));
+        // Missing coefficients count as zero.
+        assert_eq!(multi(1, &[2]) + multi(1, &[0, 7]), multi(2, &[2, 7]));
+    }
+
+    #[test]
+    fn test_multi_neutrosophic_idempotent_multiplication() {
+        let i1 = multi(0, &[1, 0]);
+        let i2 = multi(0, &[0, 1]);
+        // I_1^2 = I_1, I_2^2 = I_2, and I_1 I_2 = 0.
+        assert_eq!(i1.clone() * i1.clone(), i1);
+        assert_eq!(i2.clone() * i2.clone(), i2);
+        assert_eq!(i1 * i2, multi(0, &[0, 0]));
+
+        // (1 + 2I_1 + 3I_2)(4 + 5I_1 + 6I_2)
+        //   = 4 + (5 + 8 + 10)I_1 + (6 + 12 + 18)I_2 = 4 + 23I_1 + 36I_2
+        assert_eq!(multi(1, &[2, 3]) * multi(4, &[5, 6]), multi(4, &[23, 36]));
+    }
+
+    #[test]
+    fn test_multi_neutrosophic_single_indeterminacy_matches_neutrosophic() {
+        let x = NeutrosophicNumber::from((1, 2));
+        let y = NeutrosophicNumber::from((3, -4));
+        assert_eq!(
+            MultiNeutrosophic::from(x.clone()) * MultiNeutrosophic::from(y.clone()),
+            MultiNeutrosophic::from(x.clone() * y.clone())
+        );
+        assert_eq!(
+            MultiNeutrosophic::from(x.clone()) + MultiNeutrosophic::from(y.clone()),
+            MultiNeutrosophic::from(x + y)
+        );
+    }
+}
@@ -1,18 +1,123 @@
-use num_bigint::{BigInt, RandBigInt, ToBigInt};
+use crate::backend::{self, modpow};
+use crate::error::NeutrosophicError;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering as CmpOrdering;
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::str::FromStr;
+use core::sync::atomic::{Ordering, compiler_fence};
+use num_bigint::{BigInt, BigUint, Sign};
+#[cfg(feature = "std")]
+use num_bigint::{RandBigInt, ToBigInt};
+use num_integer::Integer;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+#[cfg(feature = "std")]
 use rand::Rng;
-use std::ops::{Add, Mul};
+
+/// The algebraic rule for squaring the indeterminacy symbol `I`.
+///
+/// Multiplication expands `(a + bI) * (c + dI)` into `ac + (ad + bc)I + bd*I^2`, and
+/// the rule decides what `bd*I^2` contributes to the coefficient of `I`.
+pub trait IndeterminacyRule {
+    /// Returns the coefficient of `I` in the product `(aI) * (bI)`.
+    fn square_coeff(a: &BigInt, b: &BigInt) -> BigInt;
+}
+
+/// The neutrosophic rule `I^2 = I`, used by the protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Idempotent;
+
+impl IndeterminacyRule for Idempotent {
+    fn square_coeff(a: &BigInt, b: &BigInt) -> BigInt {
+        backend::mul(a, b)
+    }
+}
+
+/// The dual-number rule `I^2 = 0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Nilpotent;
+
+impl IndeterminacyRule for Nilpotent {
+    fn square_coeff(_a: &BigInt, _b: &BigInt) -> BigInt {
+        BigInt::zero()
+    }
+}
+
+/// A dual number `a + bI` with `I^2 = 0`.
+pub type DualNumber = NeutrosophicNumber<Nilpotent>;
 
 /// Represents a neutrosophic number of the form `a + bI`.
 ///
 /// In the context of this cryptographic protocol, `I` is an indeterminacy
 /// symbol with the algebraic property I^2 = I. The numbers `a` and `b`
 /// are large integers, suitable for cryptographic calculations.
-#[derive(Debug, PartialEq, Eq, Clone)]
-pub struct NeutrosophicNumber {
+///
+/// The rule parameter `R` selects how `I` squares and defaults to `Idempotent`.
+/// Addition, subtraction, negation, and multiplication are available under any
+/// rule; everything else, including `pow_mod`, relies on the split representation
+/// of `I^2 = I` and is only defined for the default.
+///
+/// With the `serde` feature enabled, both components are serialized as decimal
+/// strings so that values of any size and sign round-trip exactly.
+#[derive(PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NeutrosophicNumber<R: IndeterminacyRule = Idempotent> {
     /// The real part of the neutrosophic number.
+    #[cfg_attr(feature = "serde", serde(with = "decimal_string"))]
     pub a: BigInt,
     /// The coefficient of the indeterminate part `I`.
+    #[cfg_attr(feature = "serde", serde(with = "decimal_string"))]
     pub b: BigInt,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    rule: PhantomData<R>,
+}
+
+/// Formats like a derived `Debug` on the two components, without the rule marker.
+impl<R: IndeterminacyRule> fmt::Debug for NeutrosophicNumber<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NeutrosophicNumber")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .finish()
+    }
+}
+
+impl<R: IndeterminacyRule> NeutrosophicNumber<R> {
+    /// Constructs `a + bI` under the rule `R`, e.g. `DualNumber::with_rule(a, b)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The real part.
+    /// * `b` - The indeterminate part's coefficient.
+    pub fn with_rule(a: BigInt, b: BigInt) -> Self {
+        NeutrosophicNumber {
+            a,
+            b,
+            rule: PhantomData,
+        }
+    }
+}
+
+/// Serializes a `BigInt` as a decimal string, independently of num-bigint's own serde format.
+#[cfg(feature = "serde")]
+mod decimal_string {
+    use num_bigint::BigInt;
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+    use std::str::FromStr;
+
+    pub fn serialize<S: Serializer>(value: &BigInt, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BigInt, D::Error> {
+        let digits = String::deserialize(deserializer)?;
+        BigInt::from_str(&digits).map_err(D::Error::custom)
+    }
 }
 
 impl NeutrosophicNumber {
@@ -23,7 +128,108 @@ impl NeutrosophicNumber {
     /// * `a` - The real part.
     /// * `b` - The indeterminate part's coefficient.
     pub fn new(a: BigInt, b: BigInt) -> Self {
-        NeutrosophicNumber { a, b }
+        NeutrosophicNumber::with_rule(a, b)
+    }
+
+    /// Constructs a neutrosophic number for use as a modulus.
+    ///
+    /// Unlike `new`, this enforces the positivity condition `a > 0` and `a + b > 0`,
+    /// so both projections `p1` and `p1+p2` are valid moduli for `pow_mod`.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The real part `p1`.
+    /// * `b` - The indeterminate part's coefficient `p2`.
+    ///
+    /// # Errors
+    /// Returns `NeutrosophicError::NonPositiveModulus` if `a <= 0` or `a + b <= 0`.
+    pub fn new_modulus(a: BigInt, b: BigInt) -> Result<Self, NeutrosophicError> {
+        let modulus = NeutrosophicNumber::new(a, b);
+        if !modulus.is_positive() {
+            return Err(NeutrosophicError::NonPositiveModulus);
+        }
+        Ok(modulus)
+    }
+
+    /// Constructs a `NeutrosophicNumber` from an `(a, b)` pair, as returned by `into_parts`.
+    ///
+    /// This is equivalent to `new(a, b)`.
+    pub fn from_parts((a, b): (BigInt, BigInt)) -> Self {
+        NeutrosophicNumber::new(a, b)
+    }
+
+    /// Consumes the neutrosophic number and returns its `(a, b)` components.
+    ///
+    /// Unlike cloning the public fields, this moves the `BigInt`s out without copying.
+    pub fn into_parts(self) -> (BigInt, BigInt) {
+        (self.a, self.b)
+    }
+
+    /// Constructs a `NeutrosophicNumber` from machine integers `a` and `b`.
+    ///
+    /// The inverse of `to_i128_parts` for values that fit.
+    pub fn from_i128_parts(a: i128, b: i128) -> Self {
+        NeutrosophicNumber::new(BigInt::from(a), BigInt::from(b))
+    }
+
+    /// Returns the components as `(a, b)` machine integers, if both fit in an `i128`.
+    ///
+    /// # Returns
+    /// `None` if either component lies outside `i128::MIN..=i128::MAX`.
+    pub fn to_i128_parts(&self) -> Option<(i128, i128)> {
+        Some((self.a.to_i128()?, self.b.to_i128()?))
+    }
+
+    /// Returns the components as unsigned `(a, b)`, if neither is negative.
+    ///
+    /// The inverse of building a number from two `gen_biguint` draws, as
+    /// `generate_random_neutrosophic` does.
+    ///
+    /// # Returns
+    /// `None` if either component is negative, which `pow_mod` outputs can be.
+    pub fn to_biguint_parts(&self) -> Option<(BigUint, BigUint)> {
+        Some((self.a.to_biguint()?, self.b.to_biguint()?))
+    }
+
+    /// Returns the determinate projection, obtained by setting `I = 0`: the real part `a`.
+    ///
+    /// This is the quantity `pow_mod` reduces modulo `p1`.
+    pub fn determinate(&self) -> BigInt {
+        self.a.clone()
+    }
+
+    /// Returns the total projection, obtained by setting `I = 1`: the sum `a + b`.
+    ///
+    /// This is the quantity `pow_mod` reduces modulo `p1+p2`.
+    pub fn indeterminate_total(&self) -> BigInt {
+        &self.a + &self.b
+    }
+
+    /// Returns the greatest common divisor of the real parts `a` of `self` and `other`.
+    ///
+    /// The result is non-negative; for example, two real parts are coprime exactly
+    /// when this is `1`.
+    pub fn gcd_real(&self, other: &Self) -> BigInt {
+        self.a.gcd(&other.a)
+    }
+
+    /// Returns the least common multiple of the real parts `a` of `self` and `other`.
+    ///
+    /// The result is non-negative, and zero if either real part is zero.
+    pub fn lcm_real(&self, other: &Self) -> BigInt {
+        self.a.lcm(&other.a)
+    }
+
+    /// Returns the 2x2 matrix `[[a, 0], [b, a+b]]` of multiplication by this number.
+    ///
+    /// In the basis `(1, I)`, multiplying by `a + bI` sends `1` to `a + bI` and `I` to
+    /// `(a+b)I` (since `I^2 = I`); those images are the matrix columns. Hence the
+    /// matrix of a product `n1 * n2` is the matrix product of the two matrices.
+    pub fn to_matrix(&self) -> [[BigInt; 2]; 2] {
+        [
+            [self.a.clone(), BigInt::zero()],
+            [self.b.clone(), self.indeterminate_total()],
+        ]
     }
 
     /// Checks if the neutrosophic number is positive.
@@ -34,6 +240,183 @@ impl NeutrosophicNumber {
         self.a > BigInt::from(0) && (&self.a + &self.b) > BigInt::from(0)
     }
 
+    /// Returns the additive identity `0 + 0I`.
+    ///
+    /// This is the same value as `Zero::zero`, available without importing the trait.
+    pub fn zero() -> Self {
+        NeutrosophicNumber::new(BigInt::zero(), BigInt::zero())
+    }
+
+    /// Returns the multiplicative identity `1 + 0I`.
+    ///
+    /// This is the same value as `One::one`, available without importing the trait.
+    pub fn one() -> Self {
+        NeutrosophicNumber::new(BigInt::one(), BigInt::zero())
+    }
+
+    /// Reports the sign of the indeterminate coefficient `b`.
+    ///
+    /// Returns `Less` for a negative `b`, `Equal` for zero, and `Greater` for a
+    /// positive one. `pow_mod` often yields a negative `b`, since it is the difference
+    /// of two independently reduced projections.
+    pub fn indeterminate_sign(&self) -> CmpOrdering {
+        match self.b.sign() {
+            Sign::Minus => CmpOrdering::Less,
+            Sign::NoSign => CmpOrdering::Equal,
+            Sign::Plus => CmpOrdering::Greater,
+        }
+    }
+
+    /// Returns `true` if both components are zero, i.e. this is `0 + 0I`.
+    pub fn is_zero(&self) -> bool {
+        self.a.is_zero() && self.b.is_zero()
+    }
+
+    /// Returns the effective size in bits: the larger of `a.bits()` and `b.bits()`.
+    ///
+    /// The sign is ignored, so `a + bI` and `-a - bI` have the same bit length.
+    pub fn bit_length(&self) -> u64 {
+        self.a.bits().max(self.b.bits())
+    }
+
+    /// Compares two neutrosophic numbers without data-dependent early exits.
+    ///
+    /// Both components are converted to big-endian two's-complement bytes, padded to
+    /// the longer of the two lengths by sign extension, and compared by accumulating
+    /// the XOR of every byte pair. The running time depends only on the byte lengths,
+    /// not on where the values differ, which makes it suitable for comparing
+    /// secret-derived values such as protocol responses.
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        let diff = ct_diff(&self.a, &other.a) | ct_diff(&self.b, &other.b);
+        diff == 0
+    }
+
+    /// Encodes the neutrosophic number as a compact binary string for transmission.
+    ///
+    /// The components `a` and `b` are written in order, each as a 4-byte big-endian
+    /// length, a sign byte (`0xff` negative, `0x00` zero, `0x01` positive), and the
+    /// big-endian two's-complement bytes of the value. The length counts only the
+    /// value bytes.
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for component in [&self.a, &self.b] {
+            let value = component.to_signed_bytes_be();
+            let len = u32::try_from(value.len()).expect("component too large to encode");
+            bytes.extend_from_slice(&len.to_be_bytes());
+            bytes.push(sign_byte(component.sign()));
+            bytes.extend_from_slice(&value);
+        }
+        bytes
+    }
+
+    /// Decodes a neutrosophic number produced by `to_bytes_be`.
+    ///
+    /// # Errors
+    /// Returns `NeutrosophicError::ParseError` if the input is truncated, has
+    /// trailing bytes, or a sign byte disagrees with its value.
+    pub fn from_bytes_be(bytes: &[u8]) -> Result<Self, NeutrosophicError> {
+        let (a, rest) = decode_component(bytes)?;
+        let (b, rest) = decode_component(rest)?;
+        if !rest.is_empty() {
+            return Err(NeutrosophicError::ParseError(format!(
+                "{} trailing bytes after neutrosophic number",
+                rest.len()
+            )));
+        }
+        Ok(NeutrosophicNumber::new(a, b))
+    }
+
+    /// Encodes the neutrosophic number as a compact hex string, e.g. `"+1f:-2a"`.
+    ///
+    /// Each component is written as a sign (`+` for zero and positive values, `-` for
+    /// negative ones) followed by its lowercase hex magnitude, and the components are
+    /// separated by `:`. Unlike `Display`, the output never truncates and is meant to
+    /// be parsed back with `from_hex`.
+    pub fn to_hex(&self) -> String {
+        let component = |value: &BigInt| {
+            let sign = if value.is_negative() { '-' } else { '+' };
+            format!("{}{:x}", sign, value.magnitude())
+        };
+        format!("{}:{}", component(&self.a), component(&self.b))
+    }
+
+    /// Decodes a string produced by `to_hex`.
+    ///
+    /// Hex digits may be upper or lower case, but both components must carry an
+    /// explicit sign and at least one digit.
+    ///
+    /// # Errors
+    /// Returns `NeutrosophicError::ParseError` if the input does not consist of two
+    /// signed hex components separated by a single `:`.
+    pub fn from_hex(s: &str) -> Result<Self, NeutrosophicError> {
+        let parse = |text: &str| -> Result<BigInt, NeutrosophicError> {
+            let (negative, digits) = match text.split_at_checked(1) {
+                Some(("+", digits)) => (false, digits),
+                Some(("-", digits)) => (true, digits),
+                _ => {
+                    return Err(NeutrosophicError::ParseError(format!(
+                        "{:?}: hex component must start with '+' or '-'",
+                        text
+                    )));
+                }
+            };
+            let magnitude = Some(digits)
+                .filter(|digits| !digits.starts_with(['+', '-']))
+                .and_then(|digits| BigInt::parse_bytes(digits.as_bytes(), 16))
+                .ok_or_else(|| {
+                    NeutrosophicError::ParseError(format!("{:?}: invalid hex digits", text))
+                })?;
+            Ok(if negative { -magnitude } else { magnitude })
+        };
+        let (a, b) = s.split_once(':').ok_or_else(|| {
+            NeutrosophicError::ParseError(format!("{:?}: expected two components split by ':'", s))
+        })?;
+        Ok(NeutrosophicNumber::new(parse(a)?, parse(b)?))
+    }
+
+    /// Overwrites both components with zeros in place, leaving `0 + 0I`.
+    ///
+    /// `BigInt` has no native zeroization, so each component's digit buffer is
+    /// rewritten with zero digits before it is released. Use this (or the `Secret`
+    /// wrapper, which calls it on drop) for values such as the secret key `x`.
+    pub fn wipe(&mut self) {
+        wipe_bigint(&mut self.a);
+        wipe_bigint(&mut self.b);
+    }
+
+    /// Checks if the neutrosophic number is a probable prime modulus.
+    ///
+    /// Since `pow_mod` reduces modulo both `p1` and `p1+p2`, a neutrosophic modulus
+    /// `p1 + p2*I` is considered prime when both the real part `a` and the sum `a+b`
+    /// pass the Miller-Rabin test.
+    ///
+    /// The witnesses are the first `rounds` primes (2, 3, 5, ...), so the result is
+    /// deterministic. With 12 or more rounds the test is exact for values below
+    /// 3.3 * 10^24; `rounds` is capped at the size of the built-in witness table.
+    ///
+    /// # Arguments
+    ///
+    /// * `rounds` - The number of Miller-Rabin witnesses to test.
+    pub fn is_probable_prime(&self, rounds: u32) -> bool {
+        is_probable_prime(&self.a, rounds) && is_probable_prime(&(&self.a + &self.b), rounds)
+    }
+
+    /// Divides two neutrosophic numbers exactly, if possible.
+    ///
+    /// Under `I^2 = I`, multiplication acts independently on the real part `a` and
+    /// the sum `a+b` (see `Mul`), so division does too: the real part is `a/c` and
+    /// the sum is `(a+b)/(c+d)`, giving the indeterminate coefficient
+    /// `(a+b)/(c+d) - a/c`.
+    ///
+    /// # Returns
+    /// `None` if `c` or `c+d` is zero, or if either quotient is not exact.
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        let real: BigInt = exact_div(&self.a, &other.a)?;
+        let total: BigInt = exact_div(&(&self.a + &self.b), &(&other.a + &other.b))?;
+        let term_i_val: BigInt = total - &real;
+        Some(NeutrosophicNumber::new(real, term_i_val))
+    }
+
     /// Performs modular exponentiation on neutrosophic numbers.
     ///
     /// This method implements the specific formula for neutrosophic modular exponentiation
@@ -42,11 +425,20 @@ impl NeutrosophicNumber {
     /// Formula: `(g1 + g2*I)^(x1 + x2*I) mod (p1 + p2*I)` is calculated as:
     /// `g1^x1 (mod p1) + I * [((g1+g2)^(x1+x2) (mod p1+p2)) - (g1^x1 (mod p1))]`
     ///
+    /// Because `I^2 = I`, the map `a + bI -> (a, a+b)` (setting `I = 0` and `I = 1`)
+    /// respects `Mul`, so this agrees with `g^x1 * (g^x2)^I` computed by repeated
+    /// multiplication, where `(u)^I = 1 + (u_total - 1)I`. The unit tests cross-check
+    /// the two on small inputs.
+    ///
     /// # Arguments
     ///
     /// * `self` - The base `g` of the exponentiation.
     /// * `exp` - The exponent `x`.
     /// * `modulus` - The modulus `p`.
+    ///
+    /// # Panics
+    /// Panics if `p1` or `p1+p2` is zero, or if `x1` or `x1+x2` is negative, since
+    /// `BigInt::modpow` rejects those inputs. Use `checked_pow_mod` to get an error instead.
     pub fn pow_mod(&self, exp: &Self, modulus: &Self) -> Self {
         let g1: &BigInt = &self.a;
         let g2: &BigInt = &self.b;
@@ -55,66 +447,3079 @@ impl NeutrosophicNumber {
         let p1: &BigInt = &modulus.a;
         let p2: &BigInt = &modulus.b;
 
+        let modulus_sum: BigInt = p1 + p2;
+        assert_modulus_sum_nonzero(&modulus_sum);
+
+        // Fast path: with no indeterminate parts, the sum projection repeats the real
+        // one, so a single ordinary modular exponentiation suffices.
+        if is_pure_real(self, exp, modulus) {
+            return NeutrosophicNumber::new(modpow(g1, x1, p1), BigInt::zero());
+        }
+
         // Calculate the real part: g1^x1 (mod p1)
-        let term1: BigInt = g1.modpow(x1, p1);
+        let term1: BigInt = modpow(g1, x1, p1);
 
         // Calculate the components for the indeterminate part.
         let base_sum: BigInt = g1 + g2;
         let exp_sum: BigInt = x1 + x2;
-        let modulus_sum: BigInt = p1 + p2;
 
         // Calculate the main term of the indeterminate part: (g1+g2)^(x1+x2) (mod p1+p2)
-        let term2_base: BigInt = base_sum.modpow(&exp_sum, &modulus_sum);
+        let term2_base: BigInt = modpow(&base_sum, &exp_sum, &modulus_sum);
 
         // The final value for the indeterminate part's coefficient.
         let term_i_val: BigInt = term2_base - &term1;
 
         NeutrosophicNumber::new(term1, term_i_val)
     }
-}
 
-/// Implements the addition operator `+` for `NeutrosophicNumber`.
-///
-/// Addition is performed element-wise: `(a + bI) + (c + dI) = (a+c) + (b+d)I`.
-impl Add for NeutrosophicNumber {
-    type Output = Self;
+    /// Performs modular exponentiation and returns the two projections separately.
+    ///
+    /// The result is `(real, total)` with `real = g1^x1 (mod p1)` and
+    /// `total = (g1+g2)^(x1+x2) (mod p1+p2)`, both in the range produced by
+    /// `BigInt::modpow`. `pow_mod` returns the same values as `real + (total - real)I`;
+    /// working with the pair avoids the often negative indeterminate coefficient.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The base `g` of the exponentiation.
+    /// * `exp` - The exponent `x`.
+    /// * `modulus` - The modulus `p`.
+    ///
+    /// # Panics
+    /// Panics under the same conditions as `pow_mod`.
+    pub fn pow_mod_projections(&self, exp: &Self, modulus: &Self) -> (BigInt, BigInt) {
+        let modulus_sum: BigInt = modulus.indeterminate_total();
+        assert_modulus_sum_nonzero(&modulus_sum);
+        let real: BigInt = modpow(&self.a, &exp.a, &modulus.a);
+        if is_pure_real(self, exp, modulus) {
+            // As in `pow_mod`, the sum projection repeats the real one.
+            return (real.clone(), real);
+        }
+        let total: BigInt = modpow(
+            &self.indeterminate_total(),
+            &exp.indeterminate_total(),
+            &modulus_sum,
+        );
+        (real, total)
+    }
 
-    fn add(self, other: Self) -> Self {
-        NeutrosophicNumber::new(self.a + other.a, self.b + other.b)
+    /// Performs modular exponentiation in place, replacing `self` with `self^exp mod p`.
+    ///
+    /// The result is the same as `pow_mod`; the two projections are written straight
+    /// into `a` and `b` instead of building a new number.
+    ///
+    /// # Arguments
+    ///
+    /// * `exp` - The exponent `x`.
+    /// * `modulus` - The modulus `p`.
+    ///
+    /// # Panics
+    /// Panics under the same conditions as `pow_mod`.
+    pub fn pow_mod_assign(&mut self, exp: &Self, modulus: &Self) {
+        let (real, total) = self.pow_mod_projections(exp, modulus);
+        self.b = total - &real;
+        self.a = real;
     }
-}
 
-/// Implements the multiplication operator `*` for `NeutrosophicNumber`.
-///
-/// Multiplication is defined by the property I^2 = I:
-/// `(a + bI) * (c + dI) = ac + (ad + bc + bd)I`.
-impl Mul for NeutrosophicNumber {
-    type Output = Self;
+    /// Performs modular exponentiation with an ordinary integer exponent.
+    ///
+    /// The exponent is treated as `exp + 0I`, which simplifies the `pow_mod` formula to:
+    /// `g1^exp (mod p1) + I * [((g1+g2)^exp (mod p1+p2)) - (g1^exp (mod p1))]`
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The base `g` of the exponentiation.
+    /// * `exp` - The integer exponent.
+    /// * `modulus` - The modulus `p`.
+    ///
+    /// # Panics
+    /// Panics if `p1` or `p1+p2` is zero, or if `exp` is negative.
+    pub fn pow_mod_scalar(&self, exp: &BigInt, modulus: &Self) -> Self {
+        let modulus_sum: BigInt = &modulus.a + &modulus.b;
+        assert_modulus_sum_nonzero(&modulus_sum);
+        if is_pure_real_scalar(self, modulus) {
+            return NeutrosophicNumber::new(modpow(&self.a, exp, &modulus.a), BigInt::zero());
+        }
+        let term1: BigInt = modpow(&self.a, exp, &modulus.a);
+        let base_sum: BigInt = &self.a + &self.b;
+        let term2_base: BigInt = modpow(&base_sum, exp, &modulus_sum);
+        let term_i_val: BigInt = term2_base - &term1;
+        NeutrosophicNumber::new(term1, term_i_val)
+    }
 
-    fn mul(self, other: Self) -> Self {
-        let ac = &self.a * &other.a;
-        let ad = &self.a * &other.b;
-        let bc = &self.b * &other.a;
-        let bd = &self.b * &other.b;
-        NeutrosophicNumber::new(ac, ad + bc + bd)
+    /// Performs modular exponentiation with a machine-word exponent `exp + 0I`.
+    ///
+    /// The result equals `pow_mod_scalar(&exp.into(), modulus)`. Each projection is
+    /// computed by plain square-and-multiply over the at most 64 exponent bits, which
+    /// skips building a `BigInt` exponent and the Montgomery setup of
+    /// `BigInt::modpow`, the dominant costs for small exponents such as squaring.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The base `g` of the exponentiation.
+    /// * `exp` - The integer exponent.
+    /// * `modulus` - The modulus `p`.
+    ///
+    /// # Panics
+    /// Panics if `p1` or `p1+p2` is zero.
+    pub fn pow_mod_u64(&self, exp: u64, modulus: &Self) -> Self {
+        let modulus_sum: BigInt = modulus.indeterminate_total();
+        assert_modulus_sum_nonzero(&modulus_sum);
+        if is_pure_real_scalar(self, modulus) {
+            return NeutrosophicNumber::new(modpow_u64(&self.a, exp, &modulus.a), BigInt::zero());
+        }
+        let term1: BigInt = modpow_u64(&self.a, exp, &modulus.a);
+        let term2_base: BigInt = modpow_u64(&self.indeterminate_total(), exp, &modulus_sum);
+        let term_i_val: BigInt = term2_base - &term1;
+        NeutrosophicNumber::new(term1, term_i_val)
     }
-}
 
-/// Generates a random `NeutrosophicNumber` with components of a given bit size.
-///
-/// This is a utility function for creating keys or other random values for the protocol.
-///
-/// # Arguments
-///
-/// * `rng` - A mutable reference to a random number generator.
-/// * `bit_size` - The desired bit size for the `a` and `b` components.
-pub fn generate_random_neutrosophic<R: Rng + RandBigInt>(
-    rng: &mut R,
-    bit_size: usize,
-) -> NeutrosophicNumber {
-    let bit_size_u64 = bit_size as u64;
-    // `gen_biguint` ensures the generated components are non-negative.
-    let a_val = rng.gen_biguint(bit_size_u64).to_bigint().unwrap();
-    let b_val = rng.gen_biguint(bit_size_u64).to_bigint().unwrap();
-    NeutrosophicNumber::new(a_val, b_val)
+    /// Performs modular exponentiation with a hand-written sliding-window algorithm.
+    ///
+    /// This computes the same result as `pow_mod`, but evaluates both `g1^x1 (mod p1)`
+    /// and `(g1+g2)^(x1+x2) (mod p1+p2)` by scanning the exponent from the most
+    /// significant bit and multiplying in windows of up to `window` bits, using a
+    /// table of the `2^(window-1)` odd powers of the base. It exists to benchmark
+    /// window sizes against `BigInt::modpow`.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The base `g` of the exponentiation.
+    /// * `exp` - The exponent `x`.
+    /// * `modulus` - The modulus `p`.
+    /// * `window` - The maximum window width in bits.
+    ///
+    /// # Panics
+    /// Panics if `window` is zero, if `p1` or `p1+p2` is not positive, or if `x1` or
+    /// `x1+x2` is negative.
+    pub fn pow_mod_windowed(&self, exp: &Self, modulus: &Self, window: usize) -> Self {
+        let modulus_sum: BigInt = &modulus.a + &modulus.b;
+        assert_modulus_sum_nonzero(&modulus_sum);
+        if is_pure_real(self, exp, modulus) {
+            let real = modpow_sliding_window(&self.a, &exp.a, &modulus.a, window);
+            return NeutrosophicNumber::new(real, BigInt::zero());
+        }
+        let term1: BigInt = modpow_sliding_window(&self.a, &exp.a, &modulus.a, window);
+        let base_sum: BigInt = &self.a + &self.b;
+        let exp_sum: BigInt = &exp.a + &exp.b;
+        let term2_base: BigInt = modpow_sliding_window(&base_sum, &exp_sum, &modulus_sum, window);
+        let term_i_val: BigInt = term2_base - &term1;
+        NeutrosophicNumber::new(term1, term_i_val)
+    }
+
+    /// Performs modular exponentiation, returning the canonical form of the result.
+    ///
+    /// `pow_mod` leaves the indeterminate coefficient as the raw difference of two
+    /// reduced terms, which is often negative. This variant reduces the real part into
+    /// `[0, p1)` and then the indeterminate coefficient into `[0, p1+p2)`. The sum
+    /// `a+b` changes only by a multiple of `p1+p2`, so the result represents the same
+    /// group element, and congruent computations always produce identical, bitwise
+    /// equal outputs. The sum itself may reach `p1+p2`, so compare results from other
+    /// variants with `congruent_mod` rather than `is_reduced`.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The base `g` of the exponentiation.
+    /// * `exp` - The exponent `x`.
+    /// * `modulus` - The modulus `p`.
+    ///
+    /// # Panics
+    /// Under the same conditions as `pow_mod`.
+    pub fn pow_mod_canonical(&self, exp: &Self, modulus: &Self) -> Self {
+        let result = self.pow_mod(exp, modulus);
+        let modulus_sum: BigInt = &modulus.a + &modulus.b;
+        let real: BigInt = rem_euclid(&result.a, &modulus.a);
+        // Moving the real part into range must not change the sum, so the shift is
+        // taken back out of the indeterminate coefficient before reducing it.
+        let term_i_val: BigInt = rem_euclid(&(result.indeterminate_total() - &real), &modulus_sum);
+        NeutrosophicNumber::new(real, term_i_val)
+    }
+
+    /// Performs modular exponentiation after reducing the exponent modulo a known group order.
+    ///
+    /// Both exponent components are reduced into `[0, order)` before calling `pow_mod`,
+    /// which shrinks a large `x` to the size of the order. The result equals
+    /// `pow_mod(exp, modulus)` only if `order` is a multiple of the multiplicative
+    /// order of `g1` modulo `p1` and of `g1+g2` modulo `p1+p2`. The caller must supply
+    /// a correct value: for example `p - 1` for a prime field, or `lcm(p1 - 1, p1 + p2 - 1)`
+    /// when both projections are prime and the base is coprime to both.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The base `g` of the exponentiation.
+    /// * `exp` - The exponent `x`.
+    /// * `modulus` - The modulus `p`.
+    /// * `order` - A multiple of the order of the base in both projections.
+    ///
+    /// # Panics
+    /// Panics if `order` is not positive, or if `p1` or `p1+p2` is zero.
+    pub fn pow_mod_with_order(&self, exp: &Self, modulus: &Self, order: &BigInt) -> Self {
+        assert!(order.is_positive(), "group order must be positive");
+        let reduced = NeutrosophicNumber::new(rem_euclid(&exp.a, order), rem_euclid(&exp.b, order));
+        self.pow_mod(&reduced, modulus)
+    }
+
+    /// Finds the multiplicative order of this number modulo `p` by exhaustive search.
+    ///
+    /// Returns the smallest `k >= 1` for which `pow_mod_scalar(&k, modulus)` is the
+    /// identity `1 + 0I`. Each step multiplies the previous power by the base, so the
+    /// search costs up to `order_bound` modular multiplications and is only practical
+    /// for small test moduli. In the split representation the result is the lcm of
+    /// the orders of `g1` modulo `p1` and of `g1+g2` modulo `p1+p2`.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The element `g` whose order is sought.
+    /// * `modulus` - The modulus `p`.
+    /// * `order_bound` - The largest `k` to try.
+    ///
+    /// # Returns
+    /// `None` if no `k` up to `order_bound` works, e.g. when `g` is not invertible.
+    ///
+    /// # Panics
+    /// Panics if `p1` or `p1+p2` is zero.
+    pub fn multiplicative_order(&self, modulus: &Self, order_bound: &BigInt) -> Option<BigInt> {
+        let mut power = self.pow_mod_scalar(&BigInt::one(), modulus);
+        let mut k = BigInt::one();
+        while &k <= order_bound {
+            if power.is_identity_mod(modulus) {
+                return Some(k);
+            }
+            power = power.mul_mod(self, modulus);
+            k += 1;
+        }
+        None
+    }
+
+    /// Precomputes the base and modulus sums for repeated exponentiation.
+    ///
+    /// See `PrecomputedBase` for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The base `g` of the exponentiation.
+    /// * `modulus` - The modulus `p`.
+    pub fn precompute(&self, modulus: &Self) -> PrecomputedBase {
+        PrecomputedBase {
+            g1: self.a.clone(),
+            g2: self.b.clone(),
+            base_sum: &self.a + &self.b,
+            mod_p1: modulus.a.clone(),
+            mod_sum: &modulus.a + &modulus.b,
+        }
+    }
+
+    /// Performs modular exponentiation of one base for many exponents.
+    ///
+    /// The result is element-wise identical to calling `pow_mod` for each exponent,
+    /// but the base sum `g1+g2` and modulus sum `p1+p2` are computed once per call
+    /// rather than once per exponent.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The base `g` of the exponentiation.
+    /// * `exps` - The exponents `x_i`.
+    /// * `modulus` - The modulus `p`.
+    ///
+    /// With the `rayon` feature enabled, the exponents are processed in parallel.
+    ///
+    /// # Panics
+    /// Under the same conditions as `pow_mod`.
+    pub fn pow_mod_batch(&self, exps: &[Self], modulus: &Self) -> Vec<Self> {
+        #[cfg(feature = "rayon")]
+        {
+            self.pow_mod_batch_parallel(exps, modulus)
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            self.pow_mod_batch_sequential(exps, modulus)
+        }
+    }
+
+    /// The single-threaded implementation of `pow_mod_batch`.
+    #[cfg_attr(feature = "rayon", allow(dead_code))]
+    fn pow_mod_batch_sequential(&self, exps: &[Self], modulus: &Self) -> Vec<Self> {
+        let base = self.precompute(modulus);
+        exps.iter().map(|exp| base.pow(exp)).collect()
+    }
+
+    /// The rayon-backed implementation of `pow_mod_batch`.
+    #[cfg(feature = "rayon")]
+    fn pow_mod_batch_parallel(&self, exps: &[Self], modulus: &Self) -> Vec<Self> {
+        use rayon::prelude::*;
+
+        let base = self.precompute(modulus);
+        exps.par_iter().map(|exp| base.pow(exp)).collect()
+    }
+
+    /// Performs modular exponentiation, validating the inputs instead of panicking.
+    ///
+    /// This computes the same result as `pow_mod`, but first checks that the modulus
+    /// is positive (`p1 > 0` and `p1+p2 > 0`) and that the exponent components `x1`,
+    /// `x2`, and their sum `x1+x2` are non-negative. `pow_mod` raises the sum of the
+    /// base to `x1+x2`, so a negative sum would otherwise panic inside `BigInt::modpow`.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The base `g` of the exponentiation.
+    /// * `exp` - The exponent `x`.
+    /// * `modulus` - The modulus `p`.
+    ///
+    /// # Errors
+    /// * `NeutrosophicError::NonPositiveModulus` if `p1 <= 0` or `p1+p2 < 0`.
+    /// * `NeutrosophicError::ZeroModulusSum` if `p1+p2 == 0`.
+    /// * `NeutrosophicError::NegativeExponent` if `x1 < 0`, `x2 < 0`, or `x1+x2 < 0`.
+    pub fn checked_pow_mod(&self, exp: &Self, modulus: &Self) -> Result<Self, NeutrosophicError> {
+        let zero = BigInt::from(0);
+        let modulus_sum: BigInt = &modulus.a + &modulus.b;
+
+        if modulus.a <= zero || modulus_sum < zero {
+            return Err(NeutrosophicError::NonPositiveModulus);
+        }
+        if modulus_sum == zero {
+            return Err(NeutrosophicError::ZeroModulusSum);
+        }
+        if exp.a < zero || exp.b < zero || (&exp.a + &exp.b) < zero {
+            return Err(NeutrosophicError::NegativeExponent);
+        }
+
+        Ok(self.pow_mod(exp, modulus))
+    }
+
+    /// Reduces a neutrosophic number modulo `p` in the split representation used by `pow_mod`.
+    ///
+    /// The real part is reduced to `a mod p1` and the sum to `(a+b) mod (p1+p2)`, both
+    /// with Euclidean (non-negative) remainders. The indeterminate coefficient is their
+    /// difference, so, as with `pow_mod`, it may be negative. The operation is idempotent.
+    ///
+    /// Formula: `(a + bI) mod (p1 + p2*I)` is calculated as:
+    /// `a (mod p1) + I * [((a+b) (mod p1+p2)) - (a (mod p1))]`
+    ///
+    /// # Arguments
+    ///
+    /// * `modulus` - The modulus `p`.
+    ///
+    /// # Panics
+    /// Panics if `p1` or `p1+p2` is zero.
+    pub fn normalize_mod(&self, modulus: &Self) -> Self {
+        let modulus_sum: BigInt = &modulus.a + &modulus.b;
+        let real: BigInt = rem_euclid(&self.a, &modulus.a);
+        let total: BigInt = rem_euclid(&(&self.a + &self.b), &modulus_sum);
+        let term_i_val: BigInt = total - &real;
+        NeutrosophicNumber::new(real, term_i_val)
+    }
+
+    /// Checks whether two neutrosophic numbers represent the same element modulo `p`.
+    ///
+    /// Both operands are reduced with `normalize_mod` before comparing, so values that
+    /// differ only by multiples of the modulus (for example, a negative indeterminate
+    /// coefficient returned by `pow_mod`) are recognised as equal.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The number to compare against.
+    /// * `modulus` - The modulus `p`.
+    ///
+    /// # Panics
+    /// Panics if `p1` or `p1+p2` is zero.
+    pub fn congruent_mod(&self, other: &Self, modulus: &Self) -> bool {
+        self.normalize_mod(modulus) == other.normalize_mod(modulus)
+    }
+
+    /// Lazily yields the repeated squares `g, g^2, g^4, g^8, ...` modulo `p`.
+    ///
+    /// The first item is `self` reduced with `normalize_mod`, and each further item is
+    /// the square of the previous one via `mul_mod`. These are the values a
+    /// square-and-multiply exponentiation combines; the `k`-th item equals
+    /// `pow_mod_u64(2^k, modulus)`. The iterator never ends; bound it with `take`.
+    ///
+    /// # Arguments
+    ///
+    /// * `modulus` - The modulus `p`.
+    ///
+    /// # Panics
+    /// Panics if `p1` or `p1+p2` is zero.
+    pub fn square_chain(&self, modulus: &Self) -> impl Iterator<Item = NeutrosophicNumber> {
+        core::iter::successors(Some(self.normalize_mod(modulus)), move |square| {
+            Some(square.mul_mod(square, modulus))
+        })
+    }
+
+    /// Checks whether this is the multiplicative identity modulo `p`.
+    ///
+    /// In the split representation that means `a ≡ 1 (mod p1)` and
+    /// `a+b ≡ 1 (mod p1+p2)`, so an unreduced value such as `(p1 + 1) + (p2 - p1)I`
+    /// qualifies even though it differs from the literal `one()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `modulus` - The modulus `p`.
+    ///
+    /// # Panics
+    /// Panics if `p1` or `p1+p2` is zero.
+    pub fn is_identity_mod(&self, modulus: &Self) -> bool {
+        self.congruent_mod(&NeutrosophicNumber::one(), modulus)
+    }
+
+    /// Checks whether the number is already in the reduced form produced by `normalize_mod`.
+    ///
+    /// In the split representation this means `0 <= a < p1` and `0 <= a+b < p1+p2`;
+    /// the indeterminate coefficient `b` itself may be negative.
+    ///
+    /// # Arguments
+    ///
+    /// * `modulus` - The modulus `p`.
+    pub fn is_reduced(&self, modulus: &Self) -> bool {
+        let zero = BigInt::from(0);
+        let total: BigInt = &self.a + &self.b;
+        let modulus_sum: BigInt = &modulus.a + &modulus.b;
+        self.a >= zero && self.a < modulus.a && total >= zero && total < modulus_sum
+    }
+
+    /// Multiplies two neutrosophic numbers modulo `p`.
+    ///
+    /// The product is formed with `Mul`, so the `I^2 = I` term is folded into the
+    /// indeterminate coefficient before reducing with `normalize_mod`. Equivalently,
+    /// the real part is `a*c mod p1` and the sum is `(a+b)(c+d) mod (p1+p2)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other factor.
+    /// * `modulus` - The modulus `p`.
+    ///
+    /// # Panics
+    /// Panics if `p1` or `p1+p2` is zero.
+    pub fn mul_mod(&self, other: &Self, modulus: &Self) -> Self {
+        (self * other).normalize_mod(modulus)
+    }
+
+    /// Computes the modular multiplicative inverse of a neutrosophic number.
+    ///
+    /// The inverse follows the same split representation as `pow_mod`: the real
+    /// part is inverted modulo `p1`, the sum `a+b` is inverted modulo `p1+p2`,
+    /// and the indeterminate coefficient is their difference.
+    ///
+    /// Formula: `(a + bI)^-1 mod (p1 + p2*I)` is calculated as:
+    /// `a^-1 (mod p1) + I * [((a+b)^-1 (mod p1+p2)) - (a^-1 (mod p1))]`
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The number to invert.
+    /// * `modulus` - The modulus `p`.
+    ///
+    /// # Returns
+    /// `None` if `a` is not coprime to `p1` or `a+b` is not coprime to `p1+p2`.
+    pub fn inv_mod(&self, modulus: &Self) -> Option<NeutrosophicNumber> {
+        let p1: &BigInt = &modulus.a;
+        let modulus_sum: BigInt = p1 + &modulus.b;
+
+        // Invert the real part modulo p1.
+        let real_inv: BigInt = mod_inverse(&self.a, p1)?;
+
+        // Invert the sum a+b modulo p1+p2.
+        let sum_inv: BigInt = mod_inverse(&(&self.a + &self.b), &modulus_sum)?;
+
+        let term_i_val: BigInt = sum_inv - &real_inv;
+
+        Some(NeutrosophicNumber::new(real_inv, term_i_val))
+    }
+
+    /// Computes a modular square root of a neutrosophic number.
+    ///
+    /// Like `inv_mod`, this works in the split representation: a square root of the
+    /// real part is found modulo `p1`, one of the sum `a+b` modulo `p1+p2` (both with
+    /// Tonelli-Shanks), and the indeterminate coefficient is their difference. Each
+    /// component has two roots `r` and `-r`; which one is returned is unspecified.
+    ///
+    /// # Arguments
+    ///
+    /// * `modulus` - The modulus `p`. Both `p1` and `p1+p2` should be prime.
+    ///
+    /// # Returns
+    /// `None` if `a` is not a quadratic residue modulo `p1` or `a+b` is not a
+    /// quadratic residue modulo `p1+p2`.
+    pub fn sqrt_mod(&self, modulus: &Self) -> Option<NeutrosophicNumber> {
+        let p1: &BigInt = &modulus.a;
+        let modulus_sum: BigInt = p1 + &modulus.b;
+
+        let real_root: BigInt = sqrt_mod_prime(&self.a, p1)?;
+        let sum_root: BigInt = sqrt_mod_prime(&(&self.a + &self.b), &modulus_sum)?;
+
+        let term_i_val: BigInt = sum_root - &real_root;
+
+        Some(NeutrosophicNumber::new(real_root, term_i_val))
+    }
+}
+
+/// Returns the OR of the XOR of every byte of `x` and `y`, which is zero iff `x == y`.
+///
+/// The shorter encoding is sign-extended so both byte strings have the same length
+/// and every byte is visited regardless of the values.
+fn ct_diff(x: &BigInt, y: &BigInt) -> u8 {
+    let x_bytes = x.to_signed_bytes_be();
+    let y_bytes = y.to_signed_bytes_be();
+    let len = x_bytes.len().max(y_bytes.len());
+
+    let byte_at = |bytes: &[u8], i: usize| -> u8 {
+        // Left-pad shorter encodings with copies of their sign byte.
+        let pad = if bytes[0] & 0x80 != 0 { 0xff } else { 0x00 };
+        let offset = len - bytes.len();
+        if i < offset { pad } else { bytes[i - offset] }
+    };
+
+    let mut acc = 0u8;
+    for i in 0..len {
+        acc |= byte_at(&x_bytes, i) ^ byte_at(&y_bytes, i);
+    }
+    core::hint::black_box(acc)
+}
+
+/// Maps a `Sign` to the sign byte used by `to_bytes_be`.
+fn sign_byte(sign: Sign) -> u8 {
+    match sign {
+        Sign::Minus => 0xff,
+        Sign::NoSign => 0x00,
+        Sign::Plus => 0x01,
+    }
+}
+
+/// Reads one length-prefixed component from the front of `bytes`.
+///
+/// Returns the decoded value and the remaining, unread bytes.
+fn decode_component(bytes: &[u8]) -> Result<(BigInt, &[u8]), NeutrosophicError> {
+    let truncated = || NeutrosophicError::ParseError("truncated neutrosophic bytes".to_string());
+
+    let (header, rest) = bytes.split_first_chunk::<4>().ok_or_else(truncated)?;
+    let len = u32::from_be_bytes(*header) as usize;
+    let (&sign, rest) = rest.split_first().ok_or_else(truncated)?;
+    if rest.len() < len {
+        return Err(truncated());
+    }
+    let (value, rest) = rest.split_at(len);
+
+    let value = BigInt::from_signed_bytes_be(value);
+    if sign_byte(value.sign()) != sign {
+        return Err(NeutrosophicError::ParseError(format!(
+            "sign byte {:#04x} does not match the encoded value",
+            sign
+        )));
+    }
+    Ok((value, rest))
+}
+
+/// Overwrites the digits of `value` with zeros, reusing its existing buffer.
+fn wipe_bigint(value: &mut BigInt) {
+    let zeros = vec![0u32; value.iter_u32_digits().len()];
+    // `assign_from_slice` writes into the current allocation before normalizing.
+    value.assign_from_slice(Sign::Plus, &zeros);
+    compiler_fence(Ordering::SeqCst);
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for NeutrosophicNumber {
+    fn zeroize(&mut self) {
+        self.wipe();
+    }
+}
+
+/// Small primes used as deterministic Miller-Rabin witnesses.
+const WITNESSES: [u32; 25] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+
+/// Runs the Miller-Rabin primality test on `n` using the first `rounds` witnesses.
+///
+/// Returns `false` for values below 2 and for even values other than 2.
+fn is_probable_prime(n: &BigInt, rounds: u32) -> bool {
+    let one = BigInt::from(1);
+    let two = BigInt::from(2);
+    if *n < two {
+        return false;
+    }
+    if *n == two {
+        return true;
+    }
+    if (n % &two) == BigInt::from(0) {
+        return false;
+    }
+
+    // Write n - 1 = d * 2^s with d odd.
+    let n_minus_one: BigInt = n - &one;
+    let s = n_minus_one.trailing_zeros().unwrap_or(0);
+    let d: BigInt = &n_minus_one >> s;
+
+    'witness: for &w in WITNESSES.iter().take(rounds as usize) {
+        let witness = BigInt::from(w);
+        if witness >= n_minus_one {
+            // Small n: the witness table already covers every candidate base.
+            continue;
+        }
+        let mut x = witness.modpow(&d, n);
+        if x == one || x == n_minus_one {
+            continue;
+        }
+        for _ in 1..s {
+            x = x.modpow(&two, n);
+            if x == n_minus_one {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// Builds a `NeutrosophicNumber`, reducing it modulo `p` when a modulus is given.
+///
+/// Unset components default to zero. With a modulus, `build` returns the reduced form
+/// produced by `normalize_mod`, so the caller cannot forget the reduction:
+///
+/// ```
+/// use n1rzkp::neutrosophic_numbers::{NeutrosophicBuilder, NeutrosophicNumber};
+///
+/// let n = NeutrosophicBuilder::new()
+///     .a(10)
+///     .b(5)
+///     .modulus(NeutrosophicNumber::from((7, 4)))
+///     .build();
+/// assert_eq!(n, NeutrosophicNumber::from((3, 1)));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NeutrosophicBuilder {
+    a: BigInt,
+    b: BigInt,
+    modulus: Option<NeutrosophicNumber>,
+}
+
+impl NeutrosophicBuilder {
+    /// Starts a builder for `0 + 0I` with no modulus.
+    pub fn new() -> Self {
+        NeutrosophicBuilder::default()
+    }
+
+    /// Sets the real part `a`.
+    pub fn a(mut self, a: impl Into<BigInt>) -> Self {
+        self.a = a.into();
+        self
+    }
+
+    /// Sets the indeterminate part's coefficient `b`.
+    pub fn b(mut self, b: impl Into<BigInt>) -> Self {
+        self.b = b.into();
+        self
+    }
+
+    /// Sets the modulus `p` the result is reduced by.
+    pub fn modulus(mut self, modulus: NeutrosophicNumber) -> Self {
+        self.modulus = Some(modulus);
+        self
+    }
+
+    /// Produces `a + bI`, reduced with `normalize_mod` if a modulus was set.
+    ///
+    /// # Panics
+    /// Panics if a modulus was set and its `p1` or `p1+p2` is zero.
+    pub fn build(self) -> NeutrosophicNumber {
+        let n = NeutrosophicNumber::new(self.a, self.b);
+        match self.modulus {
+            Some(modulus) => n.normalize_mod(&modulus),
+            None => n,
+        }
+    }
+}
+
+/// A base and modulus prepared for repeated `pow_mod` calls.
+///
+/// Victor exponentiates the same `g` (or `b`) modulo the same `p` in every round.
+/// Creating a `PrecomputedBase` with `NeutrosophicNumber::precompute` computes
+/// `g1+g2` and `p1+p2` once, so each subsequent `pow` only performs the two
+/// modular exponentiations.
+#[derive(Debug, Clone)]
+pub struct PrecomputedBase {
+    g1: BigInt,
+    g2: BigInt,
+    base_sum: BigInt,
+    mod_p1: BigInt,
+    mod_sum: BigInt,
+}
+
+impl PrecomputedBase {
+    /// Returns the base `g1 + g2*I` this value was prepared from.
+    pub fn base(&self) -> NeutrosophicNumber {
+        NeutrosophicNumber::new(self.g1.clone(), self.g2.clone())
+    }
+
+    /// Computes `g^exp mod p`, identical to `g.pow_mod(exp, p)`.
+    ///
+    /// # Panics
+    /// Under the same conditions as `pow_mod`.
+    pub fn pow(&self, exp: &NeutrosophicNumber) -> NeutrosophicNumber {
+        pow_mod_split(&self.g1, &self.base_sum, &self.mod_p1, &self.mod_sum, exp)
+    }
+}
+
+/// Decides whether `pow_mod` can take its pure-real fast path.
+///
+/// The test only compares the three indeterminate coefficients against zero, which
+/// costs a few instructions next to a modular exponentiation. Callers usually pass
+/// values of one kind throughout, so the branch is well predicted.
+#[inline]
+fn is_pure_real(
+    base: &NeutrosophicNumber,
+    exp: &NeutrosophicNumber,
+    modulus: &NeutrosophicNumber,
+) -> bool {
+    exp.b.is_zero() && is_pure_real_scalar(base, modulus)
+}
+
+/// Decides whether the variants taking an integer exponent `exp + 0I` can take the
+/// pure-real fast path, which leaves only the base and modulus to check.
+#[inline]
+fn is_pure_real_scalar(base: &NeutrosophicNumber, modulus: &NeutrosophicNumber) -> bool {
+    base.b.is_zero() && modulus.b.is_zero()
+}
+
+/// Rejects a modulus whose sum projection `p1+p2` is zero.
+///
+/// Every exponentiation variant checks this up front, so a modulus like `5 - 5I`
+/// fails with a message that names the neutrosophic projection rather than
+/// `BigInt::modpow`'s generic one.
+#[track_caller]
+fn assert_modulus_sum_nonzero(modulus_sum: &BigInt) {
+    assert!(
+        !modulus_sum.is_zero(),
+        "neutrosophic modulus sum p1+p2 must be nonzero"
+    );
+}
+
+/// Evaluates the `pow_mod` formula with the base and modulus sums already computed.
+fn pow_mod_split(
+    g1: &BigInt,
+    base_sum: &BigInt,
+    p1: &BigInt,
+    modulus_sum: &BigInt,
+    exp: &NeutrosophicNumber,
+) -> NeutrosophicNumber {
+    assert_modulus_sum_nonzero(modulus_sum);
+    let term1: BigInt = modpow(g1, &exp.a, p1);
+    let exp_sum: BigInt = &exp.a + &exp.b;
+    let term2_base: BigInt = modpow(base_sum, &exp_sum, modulus_sum);
+    NeutrosophicNumber::new(term1.clone(), term2_base - term1)
+}
+
+/// Computes `base^exp mod modulus` with left-to-right sliding-window exponentiation.
+///
+/// The result lies in `[0, modulus)`, matching `BigInt::modpow` for a positive modulus.
+fn modpow_sliding_window(base: &BigInt, exp: &BigInt, modulus: &BigInt, window: usize) -> BigInt {
+    assert!(window >= 1, "window size must be at least 1");
+    assert!(modulus.is_positive(), "modulus must be positive");
+    assert!(!exp.is_negative(), "exponent must be non-negative");
+
+    // Precompute the odd powers base^1, base^3, ..., base^(2^window - 1).
+    let base = rem_euclid(base, modulus);
+    let base_squared = &base * &base % modulus;
+    let mut odd_powers = vec![base];
+    for k in 1..1usize << (window - 1) {
+        let next = &odd_powers[k - 1] * &base_squared % modulus;
+        odd_powers.push(next);
+    }
+
+    let mut result = BigInt::one() % modulus;
+    let mut i = exp.bits();
+    while i > 0 {
+        let top = i - 1;
+        if !exp.bit(top) {
+            result = &result * &result % modulus;
+            i = top;
+            continue;
+        }
+        // Take the widest window [low, top] of at most `window` bits that ends in a set bit.
+        let mut low = top.saturating_sub(window as u64 - 1);
+        while !exp.bit(low) {
+            low += 1;
+        }
+        let mut value = 0usize;
+        for k in (low..=top).rev() {
+            value = (value << 1) | exp.bit(k) as usize;
+            result = &result * &result % modulus;
+        }
+        result = result * &odd_powers[value >> 1] % modulus;
+        i = low;
+    }
+    result
+}
+
+/// Returns `numerator / denominator` if the division is exact and the denominator nonzero.
+fn exact_div(numerator: &BigInt, denominator: &BigInt) -> Option<BigInt> {
+    if denominator.is_zero() || !(numerator % denominator).is_zero() {
+        return None;
+    }
+    Some(numerator / denominator)
+}
+
+/// Computes `base^exp mod modulus` by right-to-left square-and-multiply.
+///
+/// Like `BigInt::modpow`, the result is reduced with `mod_floor`, so it lies in
+/// `[0, modulus)` for a positive modulus and in `(modulus, 0]` for a negative one.
+///
+/// # Panics
+/// Panics if `modulus` is zero.
+fn modpow_u64(base: &BigInt, mut exp: u64, modulus: &BigInt) -> BigInt {
+    assert!(
+        !modulus.is_zero(),
+        "attempt to calculate with zero modulus!"
+    );
+    let mut result = BigInt::one().mod_floor(modulus);
+    let mut square = base.mod_floor(modulus);
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = (&result * &square).mod_floor(modulus);
+        }
+        exp >>= 1;
+        if exp > 0 {
+            square = (&square * &square).mod_floor(modulus);
+        }
+    }
+    result
+}
+
+/// Reduces `value` modulo `modulus` into `[0, |modulus|)`.
+fn rem_euclid(value: &BigInt, modulus: &BigInt) -> BigInt {
+    let remainder = value % modulus;
+    if remainder.is_negative() {
+        remainder + modulus.abs()
+    } else {
+        remainder
+    }
+}
+
+/// Computes the inverse of `value` modulo `modulus` with the extended Euclidean algorithm.
+///
+/// The result lies in `[0, modulus)`. Returns `None` when `value` and `modulus`
+/// are not coprime or the modulus is not positive.
+fn mod_inverse(value: &BigInt, modulus: &BigInt) -> Option<BigInt> {
+    let zero = BigInt::from(0);
+    let one = BigInt::from(1);
+    if *modulus <= zero {
+        return None;
+    }
+
+    // Invariant: old_s * value ≡ old_r (mod modulus).
+    let (mut old_r, mut r) = (rem_euclid(value, modulus), modulus.clone());
+    let (mut old_s, mut s) = (one.clone(), zero.clone());
+    while r != zero {
+        let quotient = &old_r / &r;
+        let next_r = &old_r - &quotient * &r;
+        old_r = core::mem::replace(&mut r, next_r);
+        let next_s = &old_s - &quotient * &s;
+        old_s = core::mem::replace(&mut s, next_s);
+    }
+
+    if old_r != one {
+        return None;
+    }
+    Some(rem_euclid(&old_s, modulus))
+}
+
+/// Computes a square root of `value` modulo the prime `p` with Tonelli-Shanks.
+///
+/// The result lies in `[0, p)`. Returns `None` when `value` is a quadratic
+/// non-residue, or when `p` turns out not to be prime and no root is found.
+fn sqrt_mod_prime(value: &BigInt, p: &BigInt) -> Option<BigInt> {
+    let one = BigInt::one();
+    let two = BigInt::from(2);
+    if *p < two {
+        return None;
+    }
+    let n = rem_euclid(value, p);
+    if n.is_zero() || *p == two {
+        return Some(n);
+    }
+
+    // Euler's criterion: n is a residue iff n^((p-1)/2) = 1.
+    let p_minus_one: BigInt = p - &one;
+    let euler_exp: BigInt = &p_minus_one >> 1;
+    if n.modpow(&euler_exp, p) != one {
+        return None;
+    }
+
+    // Write p - 1 = q * 2^s with q odd.
+    let s = p_minus_one.trailing_zeros()?;
+    let q: BigInt = &p_minus_one >> s;
+
+    // Find a quadratic non-residue z.
+    let mut z = two.clone();
+    while z.modpow(&euler_exp, p) != p_minus_one {
+        z += 1;
+        if z >= *p {
+            return None;
+        }
+    }
+
+    let mut m = s;
+    let mut c = z.modpow(&q, p);
+    let mut t = n.modpow(&q, p);
+    let mut root = n.modpow(&((&q + &one) >> 1), p);
+    while t != one {
+        // Find the least i with t^(2^i) = 1.
+        let mut i = 0;
+        let mut t_pow = t.clone();
+        while t_pow != one {
+            t_pow = &t_pow * &t_pow % p;
+            i += 1;
+            if i == m {
+                return None;
+            }
+        }
+        let b = c.modpow(&(BigInt::one() << (m - i - 1)), p);
+        m = i;
+        c = &b * &b % p;
+        t = t * &c % p;
+        root = root * b % p;
+    }
+
+    // Guards against a composite `p`, for which the steps above prove nothing.
+    if &root * &root % p != n {
+        return None;
+    }
+    Some(root)
+}
+
+/// Builds a `NeutrosophicNumber` from a pair of machine integers `(a, b)`.
+///
+/// This is a shorthand for small constants: `NeutrosophicNumber::from((3, 4))` is `3 + 4I`.
+impl From<(i64, i64)> for NeutrosophicNumber {
+    fn from((a, b): (i64, i64)) -> Self {
+        NeutrosophicNumber::new(BigInt::from(a), BigInt::from(b))
+    }
+}
+
+/// Builds a pure-real `NeutrosophicNumber` `a + 0I` from a `BigInt`.
+impl From<BigInt> for NeutrosophicNumber {
+    fn from(a: BigInt) -> Self {
+        NeutrosophicNumber::new(a, BigInt::from(0))
+    }
+}
+
+/// Builds a pure-real `NeutrosophicNumber` `value + 0I` from a `u64`.
+impl From<u64> for NeutrosophicNumber {
+    fn from(value: u64) -> Self {
+        NeutrosophicNumber::from(BigInt::from(value))
+    }
+}
+
+/// Builds a pure-real `NeutrosophicNumber` `value + 0I` from a `u32`.
+impl From<u32> for NeutrosophicNumber {
+    fn from(value: u32) -> Self {
+        NeutrosophicNumber::from(BigInt::from(value))
+    }
+}
+
+/// Returns the decimal strings of both components, `(a, b)`, independent of `Display`.
+impl From<&NeutrosophicNumber> for (String, String) {
+    fn from(n: &NeutrosophicNumber) -> Self {
+        (n.a.to_string(), n.b.to_string())
+    }
+}
+
+/// Formats a `NeutrosophicNumber` as `a + bI`, or `a - |b|I` when `b` is negative.
+///
+/// A precision such as `{:.50}` truncates each component's decimal digits to at
+/// most that many characters, appending `...` to any component that was cut short.
+/// This keeps 2048-bit values readable in logs.
+impl fmt::Display for NeutrosophicNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let truncate = |digits: String| -> String {
+            match f.precision() {
+                Some(max) if digits.chars().count() > max => {
+                    format!("{}...", digits.chars().take(max).collect::<String>())
+                }
+                _ => digits,
+            }
+        };
+
+        let sign = if self.b < BigInt::from(0) { '-' } else { '+' };
+        write!(
+            f,
+            "{} {} {}I",
+            truncate(self.a.to_string()),
+            sign,
+            truncate(self.b.magnitude().to_string())
+        )
+    }
+}
+
+/// Parses a `NeutrosophicNumber` written in `a + bI` notation.
+///
+/// Accepted forms, with optional whitespace around the operator and the whole string:
+/// * `"3 + 18I"` and `"3 - 1I"` (the `Display` output),
+/// * `"5"` for a pure real number (`b = 0`),
+/// * `"2I"` or `"-2I"` for a pure indeterminate number (`a = 0`).
+///
+/// Each component is decimal, parsed with `BigInt::from_str`, unless prefixed with
+/// `0x` for hexadecimal (e.g. `"0x1f + 0x2aI"`); the radix may differ between the
+/// two components. Malformed input yields `NeutrosophicError::ParseError`.
+impl FromStr for NeutrosophicNumber {
+    type Err = NeutrosophicError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let Some(body) = trimmed.strip_suffix('I') else {
+            // No indeterminate part: the whole string is the real part.
+            return Ok(NeutrosophicNumber::from(parse_component(trimmed)?));
+        };
+        let body = body.trim_end();
+
+        // The operator is the last sign that is not the leading sign of the real part.
+        match body.rfind(['+', '-']).filter(|&pos| pos > 0) {
+            Some(pos) => {
+                let real = parse_component(body[..pos].trim_end())?;
+                let magnitude = body[pos + 1..].trim_start();
+                if magnitude.starts_with(['+', '-']) {
+                    return Err(NeutrosophicError::ParseError(format!(
+                        "unexpected sign in indeterminate part of {:?}",
+                        s
+                    )));
+                }
+                let coefficient = parse_component(magnitude)?;
+                let coefficient = if body[pos..].starts_with('-') {
+                    -coefficient
+                } else {
+                    coefficient
+                };
+                Ok(NeutrosophicNumber::new(real, coefficient))
+            }
+            None => Ok(NeutrosophicNumber::new(
+                BigInt::from(0),
+                parse_component(body)?,
+            )),
+        }
+    }
+}
+
+/// Parses a single component, mapping failures to `NeutrosophicError::ParseError`.
+///
+/// Components are decimal by default; a `0x` or `0X` prefix (after an optional
+/// sign) selects hexadecimal.
+fn parse_component(text: &str) -> Result<BigInt, NeutrosophicError> {
+    let (negative, unsigned) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+
+    let Some(hex_digits) = unsigned
+        .strip_prefix("0x")
+        .or_else(|| unsigned.strip_prefix("0X"))
+    else {
+        return BigInt::from_str(text)
+            .map_err(|err| NeutrosophicError::ParseError(format!("{:?}: {}", text, err)));
+    };
+
+    // `parse_bytes` would accept a second sign after the prefix, so rule it out here.
+    let magnitude = Some(hex_digits)
+        .filter(|digits| !digits.starts_with(['+', '-']))
+        .and_then(|digits| BigInt::parse_bytes(digits.as_bytes(), 16))
+        .ok_or_else(|| NeutrosophicError::ParseError(format!("{:?}: invalid hex digits", text)))?;
+    Ok(if negative { -magnitude } else { magnitude })
+}
+
+/// Orders neutrosophic numbers by the positivity of their difference.
+///
+/// `n1 > n2` if and only if `(n1 - n2).is_positive()`, i.e. both `a1 > a2` and
+/// `a1+b1 > a2+b2`. This is only a partial order: when neither difference is
+/// positive and the numbers are not equal, `partial_cmp` returns `None`.
+impl PartialOrd for NeutrosophicNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<CmpOrdering> {
+        if self == other {
+            return Some(CmpOrdering::Equal);
+        }
+        let diff = NeutrosophicNumber::new(&self.a - &other.a, &self.b - &other.b);
+        if diff.is_positive() {
+            Some(CmpOrdering::Greater)
+        } else if (-diff).is_positive() {
+            Some(CmpOrdering::Less)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parses a `NeutrosophicNumber` from a string slice, exactly like `FromStr`.
+impl TryFrom<&str> for NeutrosophicNumber {
+    type Error = NeutrosophicError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Implements the addition operator `+` for `NeutrosophicNumber`.
+///
+/// Addition is performed element-wise: `(a + bI) + (c + dI) = (a+c) + (b+d)I`.
+impl<R: IndeterminacyRule> Add for NeutrosophicNumber<R> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        &self + &other
+    }
+}
+
+/// Implements `&n1 + &n2`, which adds without consuming or cloning either operand.
+impl<'b, R: IndeterminacyRule> Add<&'b NeutrosophicNumber<R>> for &NeutrosophicNumber<R> {
+    type Output = NeutrosophicNumber<R>;
+
+    fn add(self, other: &'b NeutrosophicNumber<R>) -> NeutrosophicNumber<R> {
+        NeutrosophicNumber::with_rule(
+            backend::add(&self.a, &other.a),
+            backend::add(&self.b, &other.b),
+        )
+    }
+}
+
+/// Implements the subtraction operator `-` for `NeutrosophicNumber`.
+///
+/// Subtraction is performed element-wise: `(a + bI) - (c + dI) = (a-c) + (b-d)I`.
+impl<R: IndeterminacyRule> Sub for NeutrosophicNumber<R> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        NeutrosophicNumber::with_rule(&self.a - &other.a, &self.b - &other.b)
+    }
+}
+
+/// Implements the division operator `/` for `NeutrosophicNumber`.
+///
+/// Division is exact: `(a + bI) / (c + dI) = a/c + ((a+b)/(c+d) - a/c)I`.
+/// Use `checked_div` to handle inexact division without panicking.
+///
+/// # Panics
+/// Panics if the division is not exact or the divisor has a zero projection.
+impl Div for NeutrosophicNumber {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self.checked_div(&other)
+            .expect("neutrosophic division is not exact")
+    }
+}
+
+/// Implements the negation operator `-` for `NeutrosophicNumber`.
+///
+/// Negation is performed element-wise: `-(a + bI) = (-a) + (-b)I`.
+impl<R: IndeterminacyRule> Neg for NeutrosophicNumber<R> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        NeutrosophicNumber::with_rule(-&self.a, -&self.b)
+    }
+}
+
+/// Implements the multiplication operator `*` for `NeutrosophicNumber`.
+///
+/// Multiplication is defined by the rule `R`. Under the default I^2 = I:
+/// `(a + bI) * (c + dI) = ac + (ad + bc + bd)I`, and under I^2 = 0 the `bd` term
+/// drops out.
+impl<R: IndeterminacyRule> Mul for NeutrosophicNumber<R> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        &self * &other
+    }
+}
+
+/// Implements `&n1 * &n2`, which multiplies without consuming or cloning either operand.
+impl<'b, R: IndeterminacyRule> Mul<&'b NeutrosophicNumber<R>> for &NeutrosophicNumber<R> {
+    type Output = NeutrosophicNumber<R>;
+
+    fn mul(self, other: &'b NeutrosophicNumber<R>) -> NeutrosophicNumber<R> {
+        let ac = backend::mul(&self.a, &other.a);
+        let ad = backend::mul(&self.a, &other.b);
+        let bc = backend::mul(&self.b, &other.a);
+        let bd = R::square_coeff(&self.b, &other.b);
+        NeutrosophicNumber::with_rule(ac, ad + bc + bd)
+    }
+}
+
+/// Implements the in-place addition operator `+=` for `NeutrosophicNumber`.
+impl AddAssign for NeutrosophicNumber {
+    fn add_assign(&mut self, other: Self) {
+        self.a += other.a;
+        self.b += other.b;
+    }
+}
+
+/// Implements the in-place subtraction operator `-=` for `NeutrosophicNumber`.
+impl SubAssign for NeutrosophicNumber {
+    fn sub_assign(&mut self, other: Self) {
+        self.a -= other.a;
+        self.b -= other.b;
+    }
+}
+
+/// Implements the in-place multiplication operator `*=` for `NeutrosophicNumber`.
+///
+/// Uses the factored form `(a + bI) * (c + dI) = ac + (ad + b(c+d))I` so that
+/// both components are updated with `BigInt`'s own assign operators.
+impl MulAssign for NeutrosophicNumber {
+    fn mul_assign(&mut self, mut other: Self) {
+        let ad = &self.a * &other.b;
+        // `other` is owned, so its `b` becomes the sum `c+d` in place.
+        other.b += &other.a;
+        self.b *= other.b;
+        self.b += ad;
+        self.a *= other.a;
+    }
+}
+
+/// The additive identity `0 + 0I`.
+impl Zero for NeutrosophicNumber {
+    fn zero() -> Self {
+        NeutrosophicNumber::zero()
+    }
+
+    fn is_zero(&self) -> bool {
+        NeutrosophicNumber::is_zero(self)
+    }
+}
+
+/// Defaults to the additive identity `0 + 0I`, matching `Zero::zero`.
+impl Default for NeutrosophicNumber {
+    fn default() -> Self {
+        Zero::zero()
+    }
+}
+
+/// The multiplicative identity `1 + 0I`.
+///
+/// `(1 + 0I) * (a + bI) = a + (0a + 1b + 0b)I = a + bI` under the `Mul` rule.
+impl One for NeutrosophicNumber {
+    fn one() -> Self {
+        NeutrosophicNumber::one()
+    }
+}
+
+/// Sums an iterator of neutrosophic numbers element-wise, starting from `0 + 0I`.
+impl Sum for NeutrosophicNumber {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Zero::zero(), |acc, n| acc + n)
+    }
+}
+
+/// Multiplies an iterator of neutrosophic numbers, starting from `1 + 0I`.
+impl Product for NeutrosophicNumber {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(One::one(), |acc, n| acc * n)
+    }
+}
+
+/// The largest component bit size accepted by the random generators.
+///
+/// Far above any parameter the protocol uses; it stops a mistyped size from
+/// allocating gigabytes before failing.
+pub const MAX_BIT_SIZE: usize = 65536;
+
+/// Generates a random `NeutrosophicNumber` with components of a given bit size.
+///
+/// This is a utility function for creating keys or other random values for the protocol.
+/// A component that comes out as zero is drawn again, so both `a` and `b` are
+/// guaranteed to be positive on return. This rules out degenerate bases and zero
+/// moduli, which would make `pow_mod` meaningless or panic.
+///
+/// # Arguments
+///
+/// * `rng` - A mutable reference to a random number generator.
+/// * `bit_size` - The desired bit size for the `a` and `b` components, in `1..=MAX_BIT_SIZE`.
+///
+/// # Panics
+/// Panics if `bit_size` is zero, since no positive value fits in zero bits, or if it
+/// exceeds `MAX_BIT_SIZE`. Use `checked_generate_random_neutrosophic` to get an
+/// error instead.
+#[cfg(feature = "std")]
+pub fn generate_random_neutrosophic<R: Rng + RandBigInt>(
+    rng: &mut R,
+    bit_size: usize,
+) -> NeutrosophicNumber {
+    assert!(bit_size >= 1, "random components need at least 1 bit");
+    assert!(
+        bit_size <= MAX_BIT_SIZE,
+        "random components are limited to MAX_BIT_SIZE bits"
+    );
+    let bit_size_u64 = bit_size as u64;
+    // `gen_biguint` ensures the generated components are non-negative; zero is rejected.
+    let mut draw_positive = || loop {
+        let value = rng.gen_biguint(bit_size_u64).to_bigint().unwrap();
+        if !value.is_zero() {
+            break value;
+        }
+    };
+    let a_val = draw_positive();
+    let b_val = draw_positive();
+    NeutrosophicNumber::new(a_val, b_val)
+}
+
+/// Generates a random `NeutrosophicNumber` after validating the bit size.
+///
+/// This is `generate_random_neutrosophic` for sizes that come from user input.
+///
+/// # Arguments
+///
+/// * `rng` - A mutable reference to a random number generator.
+/// * `bit_size` - The desired bit size for the `a` and `b` components.
+///
+/// # Errors
+/// Returns `NeutrosophicError::OutOfRange` if `bit_size` is zero or exceeds
+/// `MAX_BIT_SIZE`.
+#[cfg(feature = "std")]
+pub fn checked_generate_random_neutrosophic<R: Rng + RandBigInt>(
+    rng: &mut R,
+    bit_size: usize,
+) -> Result<NeutrosophicNumber, NeutrosophicError> {
+    if !(1..=MAX_BIT_SIZE).contains(&bit_size) {
+        return Err(NeutrosophicError::OutOfRange(format!(
+            "bit size {} is not in 1..={}",
+            bit_size, MAX_BIT_SIZE
+        )));
+    }
+    Ok(generate_random_neutrosophic(rng, bit_size))
+}
+
+/// Generates a random `NeutrosophicNumber` whose components have exactly `bit_size` bits.
+///
+/// `generate_random_neutrosophic` draws components of *at most* `bit_size` bits, so a
+/// "2048-bit" value may be a few bits shorter when its top bits happen to be zero.
+/// This variant sets the top bit of each component, guaranteeing
+/// `a.bits() == bit_size` and `b.bits() == bit_size`.
+///
+/// # Arguments
+///
+/// * `rng` - A mutable reference to a random number generator.
+/// * `bit_size` - The exact bit size for the `a` and `b` components. Must be at least 1.
+///
+/// # Panics
+/// Panics if `bit_size` is zero.
+#[cfg(feature = "std")]
+pub fn generate_random_neutrosophic_exact<R: Rng + RandBigInt>(
+    rng: &mut R,
+    bit_size: usize,
+) -> NeutrosophicNumber {
+    assert!(bit_size >= 1, "an exact bit size must be at least 1");
+    let top_bit = BigInt::from(1) << (bit_size - 1);
+    let n = generate_random_neutrosophic(rng, bit_size);
+    NeutrosophicNumber::new(n.a | &top_bit, n.b | &top_bit)
+}
+
+/// Generates a random `NeutrosophicNumber` suitable for use as a prime modulus.
+///
+/// The real part `a` is drawn as a random odd number of at most `bit_size` bits until
+/// it passes Miller-Rabin, then an even coefficient `b` is drawn until the sum `a+b`
+/// also passes. The result therefore satisfies both `is_probable_prime(rounds)` and
+/// `is_positive()`.
+///
+/// The function loops until it succeeds. By the prime number theorem a random odd
+/// `n`-bit candidate is prime with probability about `2 / (n ln 2)`, so roughly
+/// `n * 0.35` draws are expected per component (around 710 each for 2048 bits).
+///
+/// # Arguments
+///
+/// * `rng` - A mutable reference to a random number generator.
+/// * `bit_size` - The maximum bit size of the `a` and `b` components. Must be at least 2.
+/// * `rounds` - The number of Miller-Rabin rounds used to test each candidate.
+///
+/// # Panics
+/// Panics if `bit_size < 2`, since no odd prime fits in a single bit.
+#[cfg(feature = "std")]
+pub fn generate_neutrosophic_prime<R: Rng + RandBigInt>(
+    rng: &mut R,
+    bit_size: usize,
+    rounds: u32,
+) -> NeutrosophicNumber {
+    assert!(
+        bit_size >= 2,
+        "a neutrosophic prime needs at least 2 bits per component"
+    );
+    let bit_size_u64 = bit_size as u64;
+    let one = BigInt::from(1);
+
+    // Odd candidates for the real part.
+    let a_val = loop {
+        let candidate = rng.gen_biguint(bit_size_u64).to_bigint().unwrap() | &one;
+        if is_probable_prime(&candidate, rounds) {
+            break candidate;
+        }
+    };
+
+    // Even coefficients keep the sum odd.
+    let b_val = loop {
+        let candidate = rng.gen_biguint(bit_size_u64).to_bigint().unwrap() >> 1u32 << 1u32;
+        if is_probable_prime(&(&a_val + &candidate), rounds) {
+            break candidate;
+        }
+    };
+
+    NeutrosophicNumber::new(a_val, b_val)
+}
+
+/// The number of candidates `2, 3, ...` tried per projection by `find_generator`.
+const GENERATOR_SEARCH_LIMIT: u32 = 1000;
+
+/// Trial divisors below this bound are used to factor the group orders in `find_generator`.
+const TRIAL_DIVISION_LIMIT: u32 = 1 << 16;
+
+/// Searches for a generator of the multiplicative group modulo a neutrosophic prime.
+///
+/// In the split representation the group modulo `p1 + p2*I` is the product of the
+/// groups modulo `p1` and modulo `p1+p2`, so `g` generates both when its real part is
+/// a primitive root mod `p1` and its sum `g1+g2` is a primitive root mod `p1+p2`.
+/// Each projection is searched independently among the small candidates `2, 3, ...`,
+/// and the result is returned reduced modulo `p`.
+///
+/// Testing a candidate needs the prime factors of `p1 - 1` and `p1+p2 - 1`. They are
+/// found by trial division up to `2^16`, so this only succeeds when whatever is left
+/// over after that is itself prime.
+///
+/// # Arguments
+///
+/// * `p` - The neutrosophic prime modulus.
+///
+/// # Returns
+/// `None` if `p` is not a neutrosophic prime, if a group order cannot be factored, or
+/// if no primitive root is found among the first 1000 candidates of a projection.
+pub fn find_generator(p: &NeutrosophicNumber) -> Option<NeutrosophicNumber> {
+    if !p.is_probable_prime(WITNESSES.len() as u32) {
+        return None;
+    }
+    let real = find_primitive_root(&p.a)?;
+    let total = find_primitive_root(&p.indeterminate_total())?;
+    let term_i_val: BigInt = total - &real;
+    Some(NeutrosophicNumber::new(real, term_i_val))
+}
+
+/// Returns the smallest primitive root modulo the prime `p` within the search limit.
+fn find_primitive_root(p: &BigInt) -> Option<BigInt> {
+    let order: BigInt = p - 1;
+    let factors = prime_factors(&order)?;
+    (2..GENERATOR_SEARCH_LIMIT + 2)
+        .map(BigInt::from)
+        .take_while(|candidate| candidate < p)
+        .find(|candidate| {
+            factors
+                .iter()
+                .all(|q| !modpow(candidate, &(&order / q), p).is_one())
+        })
+}
+
+/// Returns the distinct prime factors of `n > 0`, or `None` if trial division up to
+/// `TRIAL_DIVISION_LIMIT` leaves a composite cofactor.
+fn prime_factors(n: &BigInt) -> Option<Vec<BigInt>> {
+    let mut factors = Vec::new();
+    let mut rest = n.clone();
+    for d in (2..TRIAL_DIVISION_LIMIT).map(BigInt::from) {
+        if &d * &d > rest {
+            break;
+        }
+        if rest.is_multiple_of(&d) {
+            while rest.is_multiple_of(&d) {
+                rest /= &d;
+            }
+            factors.push(d);
+        }
+    }
+    if !rest.is_one() {
+        if !is_probable_prime(&rest, WITNESSES.len() as u32) {
+            return None;
+        }
+        factors.push(rest);
+    }
+    Some(factors)
+}
+
+/// Counts the set bits of both components of an exponent.
+///
+/// Square-and-multiply does one multiplication per set bit, so the time `pow_mod`
+/// takes depends on this weight as well as on the bit length. Use it to group or
+/// compare exponents when measuring that leak, e.g. to check whether timings
+/// correlate with the weight of the secret `x`. It does not change how `pow_mod`
+/// runs; a constant-time ladder would make the weight irrelevant.
+///
+/// The count is taken over the stored components `a` and `b`, ignoring their signs.
+/// `pow_mod` exponentiates by `a` and `a+b`, whose weights can differ.
+///
+/// # Arguments
+///
+/// * `exp` - The exponent to measure.
+pub fn hamming_weight(exp: &NeutrosophicNumber) -> u64 {
+    exp.a.magnitude().count_ones() + exp.b.magnitude().count_ones()
+}
+
+/// Shorthand for `a + bI` in the crate's unit tests.
+#[cfg(all(test, feature = "std"))]
+pub(crate) fn neu(a: i64, b: i64) -> NeutrosophicNumber {
+    NeutrosophicNumber::from((a, b))
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn test_neutrosophic_addition() {
+        let n1 = neu(1, 2);
+        let n2 = neu(3, 4);
+        let expected = neu(4, 6);
+        assert_eq!(n1 + n2, expected);
+    }
+
+    #[test]
+    fn test_neutrosophic_subtraction() {
+        let n1 = NeutrosophicNumber::new(4.to_bigint().unwrap(), 6.to_bigint().unwrap());
+        let n2 = NeutrosophicNumber::new(3.to_bigint().unwrap(), 4.to_bigint().unwrap());
+        let expected = NeutrosophicNumber::new(1.to_bigint().unwrap(), 2.to_bigint().unwrap());
+        assert_eq!(n1 - n2, expected);
+    }
+
+    #[test]
+    fn test_neutrosophic_negation() {
+        let n = NeutrosophicNumber::new(1.to_bigint().unwrap(), 2.to_bigint().unwrap());
+        let expected =
+            NeutrosophicNumber::new((-1).to_bigint().unwrap(), (-2).to_bigint().unwrap());
+        assert_eq!(-n, expected);
+    }
+
+    #[test]
+    fn test_neutrosophic_multiplication() {
+        // Based on I^2 = I
+        let n1 = neu(1, 2);
+        let n2 = neu(3, 4);
+        // (1+2I)*(3+4I) = 1*3 + 1*4I + 2I*3 + 2I*4I = 3 + 4I + 6I + 8I^2 = 3 + 10I + 8I = 3 + 18I
+        let expected = neu(3, 18);
+        assert_eq!(n1 * n2, expected);
+    }
+
+    #[test]
+    fn test_neutrosophic_pow_mod() {
+        // g = 2+1I, x = 3+0I, p = 5+0I
+        // Real part: 2^3 mod 5 = 8 mod 5 = 3
+        // Indeterminate part: ((2+1)^(3+0) mod (5+0)) - 3 = (3^3 mod 5) - 3 = (27 mod 5) - 3 = 2 - 3 = -1
+        let g = neu(2, 1);
+        let x = neu(3, 0);
+        let p = neu(5, 0);
+        let expected = neu(3, -1);
+        assert_eq!(g.pow_mod(&x, &p), expected);
+    }
+
+    #[test]
+    fn test_neutrosophic_inv_mod() {
+        // p = 7+4I, so the real part is inverted mod 7 and the sum mod 11.
+        // n = 3+2I: 3^-1 mod 7 = 5, (3+2)^-1 mod 11 = 9, indeterminate part = 9 - 5 = 4
+        let n = NeutrosophicNumber::new(3.to_bigint().unwrap(), 2.to_bigint().unwrap());
+        let p = NeutrosophicNumber::new(7.to_bigint().unwrap(), 4.to_bigint().unwrap());
+        let expected = NeutrosophicNumber::new(5.to_bigint().unwrap(), 4.to_bigint().unwrap());
+        assert_eq!(n.inv_mod(&p), Some(expected));
+    }
+
+    #[test]
+    fn test_neutrosophic_inv_mod_not_coprime() {
+        let p = NeutrosophicNumber::new(7.to_bigint().unwrap(), 4.to_bigint().unwrap());
+        // Real part 7 is not coprime to p1 = 7.
+        let n1 = NeutrosophicNumber::new(7.to_bigint().unwrap(), 1.to_bigint().unwrap());
+        assert_eq!(n1.inv_mod(&p), None);
+        // Sum 3+8 = 11 is not coprime to p1+p2 = 11.
+        let n2 = NeutrosophicNumber::new(3.to_bigint().unwrap(), 8.to_bigint().unwrap());
+        assert_eq!(n2.inv_mod(&p), None);
+    }
+
+    #[test]
+    fn test_neutrosophic_inv_mod_round_trip() {
+        // p1 = 101 and p1+p2 = 103 are both prime, so every non-zero residue is invertible.
+        let p = NeutrosophicNumber::new(101.to_bigint().unwrap(), 2.to_bigint().unwrap());
+        let p1 = &p.a;
+        let modulus_sum = &p.a + &p.b;
+        // By Fermat, n^-1 = n^(p1-2) on the real part and n^(p1+p2-2) on the sum,
+        // i.e. the exponent (p1-2) + p2*I.
+        let fermat_exp = NeutrosophicNumber::new(p1 - 2, p.b.clone());
+        for (a, b) in [(1, 0), (2, 5), (50, 17), (99, 3)] {
+            let n = NeutrosophicNumber::new(a.to_bigint().unwrap(), b.to_bigint().unwrap());
+            let inv = n.inv_mod(&p).unwrap();
+
+            // The product must be the identity in both projections.
+            let product = n.clone() * inv.clone();
+            assert_eq!(&product.a % p1, 1.to_bigint().unwrap());
+            assert_eq!(
+                (&product.a + &product.b) % &modulus_sum,
+                1.to_bigint().unwrap()
+            );
+
+            assert_eq!(n.pow_mod(&fermat_exp, &p), inv);
+        }
+    }
+
+    #[test]
+    fn test_neutrosophic_is_probable_prime() {
+        // 7 and 7+4 = 11 are prime.
+        let p = NeutrosophicNumber::new(7.to_bigint().unwrap(), 4.to_bigint().unwrap());
+        assert!(p.is_probable_prime(12));
+        // 2 is the only even prime: 2 and 2+1 = 3.
+        let p = NeutrosophicNumber::new(2.to_bigint().unwrap(), 1.to_bigint().unwrap());
+        assert!(p.is_probable_prime(12));
+        // The Mersenne primes 2^127 - 1 (real part) and 2^89 - 1 (sum).
+        let m127: BigInt = (BigInt::from(1) << 127) - 1;
+        let m89: BigInt = (BigInt::from(1) << 89) - 1;
+        let p = NeutrosophicNumber::new(m127.clone(), m89 - m127);
+        assert!(p.is_probable_prime(12));
+    }
+
+    #[test]
+    fn test_neutrosophic_is_probable_prime_rejects_composites() {
+        // Real part 9 is composite.
+        let p = NeutrosophicNumber::new(9.to_bigint().unwrap(), 2.to_bigint().unwrap());
+        assert!(!p.is_probable_prime(12));
+        // Sum 7 + 2 = 9 is composite.
+        let p = NeutrosophicNumber::new(7.to_bigint().unwrap(), 2.to_bigint().unwrap());
+        assert!(!p.is_probable_prime(12));
+        // Components below 2 and even components are rejected.
+        let p = NeutrosophicNumber::new(1.to_bigint().unwrap(), 2.to_bigint().unwrap());
+        assert!(!p.is_probable_prime(12));
+        let p = NeutrosophicNumber::new(7.to_bigint().unwrap(), 1.to_bigint().unwrap());
+        assert!(!p.is_probable_prime(12));
+        // 561 = 3 * 11 * 17 is a Carmichael number and fools the Fermat test.
+        let p = NeutrosophicNumber::new(561.to_bigint().unwrap(), (-554).to_bigint().unwrap());
+        assert!(!p.is_probable_prime(12));
+    }
+
+    #[test]
+    fn test_generate_neutrosophic_prime() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for bit_size in [2, 8, 16, 32, 64] {
+            let p = generate_neutrosophic_prime(&mut rng, bit_size, 12);
+            assert!(p.is_probable_prime(12));
+            assert!(p.is_positive());
+            assert!(p.a.bits() <= bit_size as u64);
+            assert!(p.b.bits() <= bit_size as u64);
+        }
+    }
+
+    #[test]
+    fn test_neutrosophic_display() {
+        let n = NeutrosophicNumber::new(3.to_bigint().unwrap(), 18.to_bigint().unwrap());
+        assert_eq!(n.to_string(), "3 + 18I");
+        let n = NeutrosophicNumber::new((-3).to_bigint().unwrap(), 0.to_bigint().unwrap());
+        assert_eq!(n.to_string(), "-3 + 0I");
+    }
+
+    #[test]
+    fn test_neutrosophic_display_negative_coefficient() {
+        // The output of the pow_mod test: 3 + (-1)I
+        let n = NeutrosophicNumber::new(3.to_bigint().unwrap(), (-1).to_bigint().unwrap());
+        assert_eq!(n.to_string(), "3 - 1I");
+    }
+
+    #[test]
+    fn test_neutrosophic_display_truncation() {
+        let n = NeutrosophicNumber::new(
+            1234567.to_bigint().unwrap(),
+            (-7654321).to_bigint().unwrap(),
+        );
+        assert_eq!(format!("{:.3}", n), "123... - 765...I");
+        // Components that already fit are left untouched.
+        assert_eq!(format!("{:.7}", n), "1234567 - 7654321I");
+        let small = NeutrosophicNumber::new(12.to_bigint().unwrap(), 1234.to_bigint().unwrap());
+        assert_eq!(format!("{:.3}", small), "12 + 123...I");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_neutrosophic_serde_round_trip() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let n = generate_random_neutrosophic(&mut rng, 2048);
+        let json = serde_json::to_string(&n).unwrap();
+        let decoded: NeutrosophicNumber = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, n);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_neutrosophic_serde_negative_coefficient() {
+        let n = NeutrosophicNumber::new(3.to_bigint().unwrap(), (-1).to_bigint().unwrap());
+        let json = serde_json::to_string(&n).unwrap();
+        assert_eq!(json, r#"{"a":"3","b":"-1"}"#);
+        let decoded: NeutrosophicNumber = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, n);
+    }
+
+    #[test]
+    fn test_neutrosophic_from_tuple() {
+        let verbose = NeutrosophicNumber::new(3.to_bigint().unwrap(), 4.to_bigint().unwrap());
+        assert_eq!(NeutrosophicNumber::from((3, 4)), verbose);
+        let negative = NeutrosophicNumber::new(3.to_bigint().unwrap(), (-1).to_bigint().unwrap());
+        assert_eq!(NeutrosophicNumber::from((3, -1)), negative);
+    }
+
+    #[test]
+    fn test_neutrosophic_from_bigint() {
+        let n = NeutrosophicNumber::from(BigInt::from(5));
+        assert_eq!(n.a, BigInt::from(5));
+        assert_eq!(n.b, BigInt::from(0));
+    }
+
+    #[test]
+    fn test_neutrosophic_hash_set() {
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+        assert!(set.insert(NeutrosophicNumber::from((1, 2))));
+        assert!(set.insert(NeutrosophicNumber::from((2, 1))));
+        assert!(set.insert(NeutrosophicNumber::from((3, -1))));
+        // Duplicates collapse onto the existing entries.
+        assert!(!set.insert(NeutrosophicNumber::from((1, 2))));
+        assert!(!set.insert(NeutrosophicNumber::from((3, -1))));
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&NeutrosophicNumber::from((2, 1))));
+    }
+
+    #[test]
+    fn test_neutrosophic_ct_eq() {
+        let cases = [
+            ((3, 18), (3, 18)),
+            ((3, 18), (3, 17)),
+            ((3, -1), (3, -1)),
+            ((3, -1), (3, 255)),
+            // Differing byte lengths, including sign extension of negative values.
+            ((0, 0), (1 << 40, 0)),
+            ((-1, 0), (-(1 << 40), 0)),
+            ((-1, 5), (255, 5)),
+            ((i64::MAX, i64::MIN), (i64::MAX, i64::MIN)),
+        ];
+        for (lhs, rhs) in cases {
+            let n1 = NeutrosophicNumber::from(lhs);
+            let n2 = NeutrosophicNumber::from(rhs);
+            assert_eq!(n1.ct_eq(&n2), n1 == n2, "{} vs {}", n1, n2);
+            assert_eq!(n2.ct_eq(&n1), n1 == n2, "{} vs {}", n2, n1);
+        }
+    }
+
+    #[test]
+    fn test_neutrosophic_wipe() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut n = generate_random_neutrosophic(&mut rng, 2048);
+        n.wipe();
+        assert_eq!(n, NeutrosophicNumber::from((0, 0)));
+    }
+
+    #[test]
+    fn test_neutrosophic_checked_pow_mod() {
+        // Same values as test_neutrosophic_pow_mod.
+        let g = NeutrosophicNumber::from((2, 1));
+        let x = NeutrosophicNumber::from((3, 0));
+        let p = NeutrosophicNumber::from((5, 0));
+        assert_eq!(
+            g.checked_pow_mod(&x, &p),
+            Ok(NeutrosophicNumber::from((3, -1)))
+        );
+    }
+
+    #[test]
+    fn test_neutrosophic_checked_pow_mod_zero_modulus_sum() {
+        // p1 = 5 is fine on its own, but p1 + p2 = 0.
+        let g = NeutrosophicNumber::from((2, 1));
+        let x = NeutrosophicNumber::from((3, 0));
+        let p = NeutrosophicNumber::from((5, -5));
+        assert_eq!(
+            g.checked_pow_mod(&x, &p),
+            Err(NeutrosophicError::ZeroModulusSum)
+        );
+        let p = NeutrosophicNumber::from((0, 5));
+        assert_eq!(
+            g.checked_pow_mod(&x, &p),
+            Err(NeutrosophicError::NonPositiveModulus)
+        );
+    }
+
+    #[test]
+    fn test_neutrosophic_checked_pow_mod_negative_exponent() {
+        let g = NeutrosophicNumber::from((2, 1));
+        let p = NeutrosophicNumber::from((5, 0));
+        let x = NeutrosophicNumber::from((-3, 0));
+        assert_eq!(
+            g.checked_pow_mod(&x, &p),
+            Err(NeutrosophicError::NegativeExponent)
+        );
+    }
+
+    #[test]
+    fn test_neutrosophic_checked_pow_mod_negative_exponent_sum() {
+        // x1 + x2 = -4, which would make `BigInt::modpow` panic inside `pow_mod`.
+        let g = NeutrosophicNumber::from((3, 2));
+        let p = NeutrosophicNumber::from((7, 4));
+        let x = NeutrosophicNumber::from((1, -5));
+        assert_eq!(
+            g.checked_pow_mod(&x, &p),
+            Err(NeutrosophicError::NegativeExponent)
+        );
+    }
+
+    #[test]
+    fn test_neutrosophic_from_str() {
+        let cases = [
+            ("3 + 18I", (3, 18)),
+            ("3-1I", (3, -1)),
+            ("3 - 1I", (3, -1)),
+            ("-3 + 2I", (-3, 2)),
+            ("  -3-2I  ", (-3, -2)),
+            ("5", (5, 0)),
+            ("-5", (-5, 0)),
+            ("2I", (0, 2)),
+            ("-2I", (0, -2)),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                input.parse::<NeutrosophicNumber>(),
+                Ok(NeutrosophicNumber::from(expected)),
+                "{:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_neutrosophic_from_str_round_trip() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let random = generate_random_neutrosophic(&mut rng, 2048);
+        let negative = NeutrosophicNumber::from((3, -1));
+        let zero = NeutrosophicNumber::from((0, 0));
+        for n in [random, negative, zero] {
+            assert_eq!(n.to_string().parse::<NeutrosophicNumber>(), Ok(n));
+        }
+    }
+
+    #[test]
+    fn test_neutrosophic_from_str_rejects_malformed() {
+        for input in [
+            "", "I", "abc", "3 + I", "3 + 4J", "1 2 + 3I", "3 + -1I", "3 +",
+        ] {
+            assert!(
+                matches!(
+                    input.parse::<NeutrosophicNumber>(),
+                    Err(NeutrosophicError::ParseError(_))
+                ),
+                "{:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_neutrosophic_bytes_round_trip() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let zero = NeutrosophicNumber::from((0, 0));
+        let large = generate_random_neutrosophic(&mut rng, 2048);
+        // The output of the pow_mod test, with a negative indeterminate part.
+        let negative = NeutrosophicNumber::from((3, -1));
+        for n in [zero, large, negative] {
+            assert_eq!(NeutrosophicNumber::from_bytes_be(&n.to_bytes_be()), Ok(n));
+        }
+    }
+
+    #[test]
+    fn test_neutrosophic_bytes_layout() {
+        let bytes = NeutrosophicNumber::from((3, -1)).to_bytes_be();
+        assert_eq!(bytes, [0, 0, 0, 1, 0x01, 0x03, 0, 0, 0, 1, 0xff, 0xff]);
+    }
+
+    #[test]
+    fn test_neutrosophic_bytes_rejects_malformed() {
+        let bytes = NeutrosophicNumber::from((3, -1)).to_bytes_be();
+        // Every strict prefix is truncated.
+        for len in 0..bytes.len() {
+            assert!(matches!(
+                NeutrosophicNumber::from_bytes_be(&bytes[..len]),
+                Err(NeutrosophicError::ParseError(_))
+            ));
+        }
+        // Trailing bytes are rejected.
+        let mut extended = bytes.clone();
+        extended.push(0);
+        assert!(NeutrosophicNumber::from_bytes_be(&extended).is_err());
+        // A sign byte that disagrees with the value is rejected.
+        let mut flipped = bytes;
+        flipped[4] = 0xff;
+        assert!(NeutrosophicNumber::from_bytes_be(&flipped).is_err());
+    }
+
+    #[test]
+    fn test_neutrosophic_pow_mod_batch() {
+        let g = NeutrosophicNumber::from((3, 2));
+        let p = NeutrosophicNumber::from((101, 2));
+        let exps: Vec<NeutrosophicNumber> = [(0, 0), (1, 0), (3, 0), (4, 1), (57, 13), (200, 7)]
+            .into_iter()
+            .map(NeutrosophicNumber::from)
+            .collect();
+        let expected: Vec<NeutrosophicNumber> = exps.iter().map(|x| g.pow_mod(x, &p)).collect();
+        assert_eq!(g.pow_mod_batch(&exps, &p), expected);
+        assert!(g.pow_mod_batch(&[], &p).is_empty());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_neutrosophic_pow_mod_batch_parallel_matches_sequential() {
+        let mut rng = StdRng::seed_from_u64(13);
+        let g = generate_random_neutrosophic(&mut rng, 256);
+        let p = generate_neutrosophic_prime(&mut rng, 256, 12);
+        let exps: Vec<NeutrosophicNumber> = (0..64)
+            .map(|_| generate_random_neutrosophic(&mut rng, 256))
+            .collect();
+        // pow_mod_batch runs in parallel under this feature; compare it to plain pow_mod.
+        let sequential: Vec<NeutrosophicNumber> = exps.iter().map(|x| g.pow_mod(x, &p)).collect();
+        assert_eq!(g.pow_mod_batch(&exps, &p), sequential);
+    }
+
+    #[test]
+    fn test_neutrosophic_partial_ord_comparable() {
+        use std::cmp::Ordering;
+
+        // (5+2I) - (3+1I) = 2+1I is positive.
+        let n1 = NeutrosophicNumber::from((5, 2));
+        let n2 = NeutrosophicNumber::from((3, 1));
+        assert_eq!(n1.partial_cmp(&n2), Some(Ordering::Greater));
+        assert_eq!(n2.partial_cmp(&n1), Some(Ordering::Less));
+        assert!(n1 > n2);
+        assert!(n2 < n1);
+        // (5-1I) - (3+1I) = 2-2I is not positive since 2 + (-2) = 0, nor is its negation.
+        let n3 = NeutrosophicNumber::from((5, -1));
+        assert_eq!(n3.partial_cmp(&n2), None);
+        // (5+0I) - (3+1I) = 2-1I is positive: 2 > 0 and 2 + (-1) > 0.
+        let n4 = NeutrosophicNumber::from((5, 0));
+        assert!(n4 > n2);
+    }
+
+    #[test]
+    fn test_neutrosophic_partial_ord_equal() {
+        use std::cmp::Ordering;
+
+        let n1 = NeutrosophicNumber::from((3, -1));
+        let n2 = NeutrosophicNumber::from((3, -1));
+        assert_eq!(n1.partial_cmp(&n2), Some(Ordering::Equal));
+        assert!(n1 <= n2);
+        assert!(n1 >= n2);
+    }
+
+    #[test]
+    fn test_neutrosophic_partial_ord_incomparable() {
+        // (1+5I) - (2+0I) = -1+5I and (2+0I) - (1+5I) = 1-5I are both not positive.
+        let n1 = NeutrosophicNumber::from((1, 5));
+        let n2 = NeutrosophicNumber::from((2, 0));
+        assert_eq!(n1.partial_cmp(&n2), None);
+        assert_eq!(n2.partial_cmp(&n1), None);
+        assert_ne!(n1, n2);
+    }
+
+    #[test]
+    fn test_neutrosophic_zero_and_one() {
+        use num_traits::{One, Zero};
+
+        let n = NeutrosophicNumber::from((3, -1));
+        let zero = <NeutrosophicNumber as Zero>::zero();
+        let one = <NeutrosophicNumber as One>::one();
+        assert_eq!(zero.clone() + n.clone(), n);
+        assert_eq!(one.clone() * n.clone(), n);
+        assert!(Zero::is_zero(&zero));
+        assert!(!Zero::is_zero(&NeutrosophicNumber::from((0, 1))));
+        assert!(one.is_one());
+    }
+
+    #[test]
+    fn test_neutrosophic_sum_and_product() {
+        let n1 = NeutrosophicNumber::from((1, 2));
+        let n2 = NeutrosophicNumber::from((3, 4));
+        let sum: NeutrosophicNumber = vec![n1.clone(), n2.clone()].into_iter().sum();
+        assert_eq!(sum, NeutrosophicNumber::from((4, 6)));
+        let product: NeutrosophicNumber = vec![n1, n2].into_iter().product();
+        assert_eq!(product, NeutrosophicNumber::from((3, 18)));
+        let empty: NeutrosophicNumber = Vec::new().into_iter().sum();
+        assert_eq!(empty, NeutrosophicNumber::from((0, 0)));
+    }
+
+    #[test]
+    fn test_neutrosophic_normalize_mod() {
+        let p = NeutrosophicNumber::from((7, 4));
+        // 100 mod 7 = 2 and (100 + 23) mod 11 = 2, so the coefficient is 2 - 2 = 0.
+        let n = NeutrosophicNumber::from((100, 23));
+        assert_eq!(n.normalize_mod(&p), NeutrosophicNumber::from((2, 0)));
+        // Negative values reduce to non-negative projections: -1 mod 7 = 6, -3 mod 11 = 8.
+        let n = NeutrosophicNumber::from((-1, -2));
+        assert_eq!(n.normalize_mod(&p), NeutrosophicNumber::from((6, 2)));
+    }
+
+    #[test]
+    fn test_neutrosophic_normalize_mod_canonical_range() {
+        let mut rng = StdRng::seed_from_u64(31);
+        let p = generate_neutrosophic_prime(&mut rng, 128, 12);
+        let modulus_sum = &p.a + &p.b;
+        let zero = BigInt::from(0);
+        for _ in 0..20 {
+            let n1 = generate_random_neutrosophic(&mut rng, 512);
+            let n2 = generate_random_neutrosophic(&mut rng, 512);
+            let large = n1 * -n2;
+            let reduced = large.normalize_mod(&p);
+            assert!(reduced.a >= zero && reduced.a < p.a);
+            let total = &reduced.a + &reduced.b;
+            assert!(total >= zero && total < modulus_sum);
+            // Reducing again changes nothing.
+            assert_eq!(reduced.normalize_mod(&p), reduced);
+        }
+    }
+
+    #[test]
+    fn test_neutrosophic_normalize_mod_fixes_pow_mod_output() {
+        // pow_mod already returns reduced projections, e.g. 3 + (-1)I mod 5.
+        let g = NeutrosophicNumber::from((2, 1));
+        let x = NeutrosophicNumber::from((3, 0));
+        let p = NeutrosophicNumber::from((5, 0));
+        let out = g.pow_mod(&x, &p);
+        assert_eq!(out.normalize_mod(&p), out);
+    }
+
+    #[test]
+    fn test_neutrosophic_pow_mod_canonical() {
+        // Same values as test_neutrosophic_pow_mod, where pow_mod returns 3 + (-1)I.
+        let g = NeutrosophicNumber::from((2, 1));
+        let x = NeutrosophicNumber::from((3, 0));
+        let p = NeutrosophicNumber::from((5, 0));
+        assert_eq!(g.pow_mod(&x, &p), NeutrosophicNumber::from((3, -1)));
+        // -1 mod 5 = 4
+        assert_eq!(
+            g.pow_mod_canonical(&x, &p),
+            NeutrosophicNumber::from((3, 4))
+        );
+
+        // A negative modulus makes pow_mod's terms non-positive; the canonical form
+        // does not depend on that sign convention.
+        let negative = NeutrosophicNumber::from((-7, -4));
+        let positive = NeutrosophicNumber::from((7, 4));
+        let g = NeutrosophicNumber::from((3, 2));
+        let x = NeutrosophicNumber::from((4, 1));
+        assert_ne!(g.pow_mod(&x, &negative), g.pow_mod(&x, &positive));
+        assert_eq!(
+            g.pow_mod_canonical(&x, &negative),
+            g.pow_mod_canonical(&x, &positive)
+        );
+    }
+
+    #[test]
+    fn test_neutrosophic_pow_mod_canonical_congruent_inputs() {
+        // p = 7+4I: the real part lives mod 7 (group order 6), the sum mod 11 (order 10).
+        let p = NeutrosophicNumber::from((7, 4));
+        let g = NeutrosophicNumber::from((3, 2));
+        let x = NeutrosophicNumber::from((4, 1));
+        let expected = g.pow_mod_canonical(&x, &p);
+        let modulus_sum = &p.a + &p.b;
+        assert!(expected.b >= BigInt::from(0) && expected.b < modulus_sum);
+
+        // A congruent base: g + p.
+        let g_shifted = g.clone() + p.clone();
+        assert_eq!(g_shifted.pow_mod_canonical(&x, &p), expected);
+        // A congruent exponent: real part + 6, sum + 10.
+        let x_shifted = x.clone() + NeutrosophicNumber::from((6, 4));
+        assert_eq!(g.pow_mod_canonical(&x_shifted, &p), expected);
+    }
+
+    #[test]
+    fn test_neutrosophic_division() {
+        // The inverse of test_neutrosophic_multiplication: (3+18I) / (3+4I) = 1+2I
+        let n1 = NeutrosophicNumber::from((3, 18));
+        let n2 = NeutrosophicNumber::from((3, 4));
+        let expected = NeutrosophicNumber::from((1, 2));
+        assert_eq!(n1.checked_div(&n2), Some(expected.clone()));
+        assert_eq!(n1 / n2, expected);
+    }
+
+    #[test]
+    fn test_neutrosophic_checked_div_rejects_inexact() {
+        let n = NeutrosophicNumber::from((3, 18));
+        // 3 / 2 is not exact.
+        assert_eq!(n.checked_div(&NeutrosophicNumber::from((2, 1))), None);
+        // 3 / 3 is exact, but 21 / 5 is not.
+        assert_eq!(n.checked_div(&NeutrosophicNumber::from((3, 2))), None);
+        // 3 + (-3)I has a zero sum.
+        assert_eq!(n.checked_div(&NeutrosophicNumber::from((3, -3))), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "neutrosophic division is not exact")]
+    fn test_neutrosophic_division_panics_when_inexact() {
+        let _ = NeutrosophicNumber::from((3, 18)) / NeutrosophicNumber::from((2, 1));
+    }
+
+    #[test]
+    fn test_neutrosophic_pow_mod_scalar() {
+        // Same values as test_neutrosophic_pow_mod, with x = 3 as a plain integer.
+        let g = NeutrosophicNumber::from((2, 1));
+        let p = NeutrosophicNumber::from((5, 0));
+        let expected = NeutrosophicNumber::from((3, -1));
+        assert_eq!(g.pow_mod_scalar(&BigInt::from(3), &p), expected);
+    }
+
+    #[test]
+    fn test_neutrosophic_pow_mod_scalar_matches_pow_mod() {
+        let g = NeutrosophicNumber::from((3, 2));
+        let p = NeutrosophicNumber::from((101, 2));
+        for exp in [0i64, 1, 2, 17, 100, 12345] {
+            let as_neutrosophic = NeutrosophicNumber::from((exp, 0));
+            assert_eq!(
+                g.pow_mod_scalar(&BigInt::from(exp), &p),
+                g.pow_mod(&as_neutrosophic, &p)
+            );
+        }
+    }
+
+    #[test]
+    fn test_precomputed_base_matches_pow_mod() {
+        let mut rng = StdRng::seed_from_u64(37);
+        let g = generate_random_neutrosophic(&mut rng, 256);
+        let p = generate_neutrosophic_prime(&mut rng, 256, 12);
+        let base = g.precompute(&p);
+        assert_eq!(base.base(), g);
+        for _ in 0..8 {
+            let exp = generate_random_neutrosophic(&mut rng, 256);
+            assert_eq!(base.pow(&exp), g.pow_mod(&exp, &p));
+        }
+    }
+
+    #[test]
+    fn test_neutrosophic_mul_mod() {
+        let p = NeutrosophicNumber::from((7, 4));
+        // (3+2I)*(5+4I) = 15 + (12+10+8)I = 15+30I
+        // Real part 15 mod 7 = 1, sum 45 mod 11 = 1, so the product reduces to 1+0I.
+        let n1 = NeutrosophicNumber::from((3, 2));
+        let n2 = NeutrosophicNumber::from((5, 4));
+        assert_eq!(n1.mul_mod(&n2, &p), NeutrosophicNumber::from((1, 0)));
+        // (2+1I)*(3+0I) = 6+3I: real part 6 mod 7 = 6, sum 9 mod 11 = 9.
+        let n3 = NeutrosophicNumber::from((2, 1));
+        let n4 = NeutrosophicNumber::from((3, 0));
+        assert_eq!(n3.mul_mod(&n4, &p), NeutrosophicNumber::from((6, 3)));
+    }
+
+    #[test]
+    fn test_neutrosophic_mul_mod_matches_reduced_product() {
+        let p = NeutrosophicNumber::from((101, 2));
+        for (lhs, rhs) in [((3, 2), (5, 4)), ((100, -7), (42, 61)), ((-5, 9), (8, -3))] {
+            let n1 = NeutrosophicNumber::from(lhs);
+            let n2 = NeutrosophicNumber::from(rhs);
+            let expected = (n1.clone() * n2.clone()).normalize_mod(&p);
+            assert_eq!(n1.mul_mod(&n2, &p), expected);
+        }
+        // A number times its inverse is the identity.
+        let n = NeutrosophicNumber::from((50, 17));
+        let inv = n.inv_mod(&p).unwrap();
+        assert_eq!(n.mul_mod(&inv, &p), NeutrosophicNumber::from((1, 0)));
+    }
+
+    #[test]
+    fn test_neutrosophic_from_str_hex() {
+        let cases = [
+            ("0x1f + 0x2aI", (31, 42)),
+            ("0X1F-0x2AI", (31, -42)),
+            ("-0x1f", (-31, 0)),
+            ("0x2aI", (0, 42)),
+            // Mixed radices: decimal real part, hex indeterminate part, and vice versa.
+            ("31 + 0x2aI", (31, 42)),
+            ("0x1f - 42I", (31, -42)),
+            ("31 + 42I", (31, 42)),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                NeutrosophicNumber::try_from(input),
+                Ok(NeutrosophicNumber::from(expected)),
+                "{:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_neutrosophic_projections() {
+        let n = NeutrosophicNumber::from((3, 18));
+        assert_eq!(n.determinate(), BigInt::from(3));
+        assert_eq!(n.indeterminate_total(), BigInt::from(21));
+        // Multiplication acts independently on each projection: (2+1I)*(3+4I) = 6+15I
+        let m = NeutrosophicNumber::from((2, 1)) * NeutrosophicNumber::from((3, 4));
+        assert_eq!(m.determinate(), BigInt::from(2 * 3));
+        assert_eq!(m.indeterminate_total(), BigInt::from(3 * 7));
+    }
+
+    #[test]
+    fn test_neutrosophic_congruent_mod() {
+        // pow_mod yields 3 + (-1)I, while pow_mod_canonical yields 3 + 4I for the same input.
+        let g = NeutrosophicNumber::from((2, 1));
+        let x = NeutrosophicNumber::from((3, 0));
+        let p = NeutrosophicNumber::from((5, 0));
+        let raw = g.pow_mod(&x, &p);
+        let canonical = g.pow_mod_canonical(&x, &p);
+        assert_ne!(raw, canonical);
+        assert!(raw.congruent_mod(&canonical, &p));
+
+        // Values differing by the modulus are congruent; other values are not.
+        let shifted = raw.clone() + p.clone() + p.clone();
+        assert!(shifted.congruent_mod(&raw, &p));
+        assert!(!raw.congruent_mod(&NeutrosophicNumber::from((3, 0)), &p));
+    }
+
+    #[test]
+    fn test_generate_random_neutrosophic_exact() {
+        let mut rng = StdRng::seed_from_u64(41);
+        for bit_size in [1, 2, 8, 63, 64, 65, 2048] {
+            for _ in 0..10 {
+                let n = generate_random_neutrosophic_exact(&mut rng, bit_size);
+                assert_eq!(n.a.bits(), bit_size as u64);
+                assert_eq!(n.b.bits(), bit_size as u64);
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_random_neutrosophic_rejects_zero() {
+        // With a single bit, each component is zero half of the time before re-drawing.
+        let mut rng = StdRng::seed_from_u64(43);
+        for _ in 0..64 {
+            let n = generate_random_neutrosophic(&mut rng, 1);
+            assert_eq!(n, NeutrosophicNumber::from((1, 1)));
+        }
+        for _ in 0..64 {
+            let n = generate_random_neutrosophic(&mut rng, 2);
+            assert!(n.a > BigInt::from(0) && n.b > BigInt::from(0));
+        }
+    }
+
+    #[test]
+    fn test_neutrosophic_into_parts_from_parts() {
+        let mut rng = StdRng::seed_from_u64(47);
+        let n = generate_random_neutrosophic(&mut rng, 2048);
+        assert_eq!(NeutrosophicNumber::from_parts(n.clone().into_parts()), n);
+
+        let (a, b) = NeutrosophicNumber::from((3, -1)).into_parts();
+        assert_eq!((a, b), (BigInt::from(3), BigInt::from(-1)));
+    }
+
+    #[test]
+    fn test_neutrosophic_bit_length_and_is_zero() {
+        let mut rng = StdRng::seed_from_u64(61);
+        let large = generate_random_neutrosophic_exact(&mut rng, 2048);
+        assert_eq!(large.bit_length(), 2048);
+        assert!(!large.is_zero());
+
+        // A pure-real number takes its size from `a` alone.
+        let real = NeutrosophicNumber::from((255, 0));
+        assert_eq!(real.bit_length(), 8);
+        assert!(!real.is_zero());
+
+        let zero = NeutrosophicNumber::from((0, 0));
+        assert_eq!(zero.bit_length(), 0);
+        assert!(zero.is_zero());
+    }
+
+    #[test]
+    fn test_neutrosophic_assign_ops_match_binary_ops() {
+        let n = NeutrosophicNumber::from((1, 2));
+        let other = NeutrosophicNumber::from((3, 4));
+
+        let mut sum = n.clone();
+        sum += other.clone();
+        assert_eq!(sum, n.clone() + other.clone());
+
+        let mut difference = n.clone();
+        difference -= other.clone();
+        assert_eq!(difference, n.clone() - other.clone());
+
+        let mut product = n.clone();
+        product *= other.clone();
+        assert_eq!(product, n.clone() * other.clone());
+
+        // An accumulator loop gives the same result as the fold in `Product`.
+        let factors = [(2, -1), (5, 3), (-7, 4)].map(NeutrosophicNumber::from);
+        let mut acc = n.clone();
+        for factor in factors.iter().cloned() {
+            acc *= factor;
+        }
+        assert_eq!(acc, n * factors.into_iter().product());
+    }
+
+    /// Generates a `BigInt` of up to 256 bits with either sign, so that sums and
+    /// products cross limb boundaries and mix signs.
+    fn arb_bigint() -> impl Strategy<Value = BigInt> {
+        (any::<bool>(), prop::collection::vec(any::<u8>(), 0..=32)).prop_map(|(negative, bytes)| {
+            let sign = if negative { Sign::Minus } else { Sign::Plus };
+            BigInt::from_bytes_be(sign, &bytes)
+        })
+    }
+
+    /// Generates a neutrosophic number with independent `arb_bigint` components.
+    fn arb_neutrosophic() -> impl Strategy<Value = NeutrosophicNumber> {
+        (arb_bigint(), arb_bigint()).prop_map(|(a, b)| NeutrosophicNumber::new(a, b))
+    }
+
+    /// Generates a modulus `p1 + p2*I` with `p1 >= 2` and `p1 + p2 >= 2`, as `pow_mod`
+    /// requires.
+    fn arb_modulus() -> impl Strategy<Value = NeutrosophicNumber> {
+        (2u64..u64::MAX, 2u64..u64::MAX).prop_map(|(p1, sum)| {
+            NeutrosophicNumber::new(BigInt::from(p1), BigInt::from(sum) - BigInt::from(p1))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_add_is_associative_and_commutative(
+            x in arb_neutrosophic(),
+            y in arb_neutrosophic(),
+            z in arb_neutrosophic(),
+        ) {
+            prop_assert_eq!(x.clone() + y.clone(), y.clone() + x.clone());
+            prop_assert_eq!((x.clone() + y.clone()) + z.clone(), x + (y + z));
+        }
+
+        #[test]
+        fn prop_mul_is_associative_and_commutative(
+            x in arb_neutrosophic(),
+            y in arb_neutrosophic(),
+            z in arb_neutrosophic(),
+        ) {
+            prop_assert_eq!(x.clone() * y.clone(), y.clone() * x.clone());
+            prop_assert_eq!((x.clone() * y.clone()) * z.clone(), x * (y * z));
+        }
+
+        #[test]
+        fn prop_mul_distributes_over_add(
+            x in arb_neutrosophic(),
+            y in arb_neutrosophic(),
+            z in arb_neutrosophic(),
+        ) {
+            prop_assert_eq!(
+                x.clone() * (y.clone() + z.clone()),
+                x.clone() * y.clone() + x.clone() * z.clone()
+            );
+            prop_assert_eq!((y.clone() + z.clone()) * x.clone(), y * x.clone() + z * x);
+        }
+
+        #[test]
+        fn prop_pow_mod_unit_exponent_reduces_base(
+            g in arb_neutrosophic(),
+            p in arb_modulus(),
+        ) {
+            let one = NeutrosophicNumber::from((1, 0));
+            prop_assert_eq!(g.pow_mod(&one, &p), g.normalize_mod(&p));
+        }
+    }
+
+    #[test]
+    fn test_neutrosophic_sqrt_mod_small_primes() {
+        // p = 7+4I: the squares are {1, 2, 4} mod 7 and {1, 3, 4, 5, 9} mod 11.
+        let p = NeutrosophicNumber::from((7, 4));
+
+        // 2 + 3I has real part 2 = 3^2 mod 7 and sum 5 = 4^2 mod 11.
+        let value = NeutrosophicNumber::from((2, 3));
+        let root = value.sqrt_mod(&p).unwrap();
+        assert!([3, 4].map(BigInt::from).contains(&root.a));
+        assert!([4, 7].map(BigInt::from).contains(&(&root.a + &root.b)));
+        assert!(root.mul_mod(&root, &p).congruent_mod(&value, &p));
+
+        // 3 is a non-residue mod 7, and 2 + 4I has the non-residue sum 6 mod 11.
+        assert_eq!(NeutrosophicNumber::from((3, 0)).sqrt_mod(&p), None);
+        assert_eq!(NeutrosophicNumber::from((2, 4)).sqrt_mod(&p), None);
+
+        // 13 and 17 are both 1 mod 4, which exercises the Tonelli-Shanks loop.
+        let p = NeutrosophicNumber::from((13, 4));
+        for a in 0..13 {
+            let x = NeutrosophicNumber::from((a, 3));
+            let square = x.mul_mod(&x, &p);
+            let root = square.sqrt_mod(&p).unwrap();
+            assert!(root.mul_mod(&root, &p).congruent_mod(&square, &p));
+        }
+    }
+
+    #[test]
+    fn test_neutrosophic_sqrt_mod_of_squares() {
+        let mut rng = StdRng::seed_from_u64(67);
+        let p = generate_neutrosophic_prime(&mut rng, 64, 12);
+        for _ in 0..16 {
+            let x = generate_random_neutrosophic(&mut rng, 64);
+            let square = x.mul_mod(&x, &p);
+            let root = square.sqrt_mod(&p).unwrap();
+            assert!(root.mul_mod(&root, &p).congruent_mod(&square, &p));
+        }
+    }
+
+    #[test]
+    fn test_neutrosophic_default_is_zero() {
+        assert_eq!(
+            NeutrosophicNumber::default(),
+            NeutrosophicNumber::new(0.into(), 0.into())
+        );
+
+        #[derive(Default)]
+        struct Holder {
+            value: NeutrosophicNumber,
+        }
+        assert!(Holder::default().value.is_zero());
+    }
+
+    #[test]
+    fn test_neutrosophic_pow_mod_windowed_matches_pow_mod() {
+        let mut rng = StdRng::seed_from_u64(73);
+        let p = generate_neutrosophic_prime(&mut rng, 64, 12);
+        for window in 1..=6 {
+            for _ in 0..32 {
+                let g = generate_random_neutrosophic(&mut rng, 64);
+                let x = generate_random_neutrosophic(&mut rng, 32);
+                assert_eq!(g.pow_mod_windowed(&x, &p, window), g.pow_mod(&x, &p));
+            }
+            // Zero exponents and a negative base component are handled too.
+            let g = NeutrosophicNumber::from((-5, 9));
+            for x in [(0, 0), (1, 0), (0, 3), (255, 1)] {
+                let x = NeutrosophicNumber::from(x);
+                assert_eq!(g.pow_mod_windowed(&x, &p, window), g.pow_mod(&x, &p));
+            }
+        }
+    }
+
+    #[test]
+    fn test_neutrosophic_into_string_pair() {
+        let pair: (String, String) = (&NeutrosophicNumber::from((3, 18))).into();
+        assert_eq!(pair, ("3".into(), "18".into()));
+        let pair: (String, String) = (&NeutrosophicNumber::from((3, -1))).into();
+        assert_eq!(pair, ("3".into(), "-1".into()));
+    }
+
+    #[test]
+    fn test_neutrosophic_is_reduced() {
+        let mut rng = StdRng::seed_from_u64(79);
+        let p = generate_neutrosophic_prime(&mut rng, 64, 12);
+        let x = generate_random_neutrosophic_exact(&mut rng, 256);
+        let y = generate_random_neutrosophic_exact(&mut rng, 256);
+
+        let product = x * y;
+        assert!(!product.is_reduced(&p));
+        assert!(product.normalize_mod(&p).is_reduced(&p));
+
+        // A negative coefficient is fine as long as the sum is in range.
+        let p = NeutrosophicNumber::from((7, 4));
+        assert!(NeutrosophicNumber::from((4, -3)).is_reduced(&p));
+        assert!(!NeutrosophicNumber::from((4, -5)).is_reduced(&p));
+        assert!(!NeutrosophicNumber::from((7, 0)).is_reduced(&p));
+    }
+
+    #[test]
+    fn test_neutrosophic_reference_operators() {
+        let n1 = NeutrosophicNumber::from((1, 2));
+        let n2 = NeutrosophicNumber::from((3, -4));
+
+        let sum = &n1 + &n2;
+        let product = &n1 * &n2;
+        // Both operands are still usable, since nothing was moved.
+        assert_eq!(sum, n1.clone() + n2.clone());
+        assert_eq!(product, n1.clone() * n2.clone());
+
+        let mut acc = NeutrosophicNumber::from((1, 0));
+        for _ in 0..3 {
+            acc = &acc * &n1;
+        }
+        assert_eq!(acc, [n1.clone(), n1.clone(), n1].into_iter().product());
+    }
+
+    #[test]
+    fn test_neutrosophic_pow_mod_with_order() {
+        let mut rng = StdRng::seed_from_u64(101);
+        // p = 11 + 0I: both projections are the prime 11, so Fermat gives order 10.
+        let p = NeutrosophicNumber::from((11, 0));
+        let g = NeutrosophicNumber::from((2, 5));
+        let order = BigInt::from(10);
+        for _ in 0..16 {
+            let x = generate_random_neutrosophic(&mut rng, 256);
+            assert_eq!(g.pow_mod_with_order(&x, &p, &order), g.pow_mod(&x, &p));
+        }
+
+        // p = 7 + 4I has projections 7 and 11, so lcm(6, 10) = 30 works for both.
+        let p = NeutrosophicNumber::from((7, 4));
+        let order = BigInt::from(30);
+        for _ in 0..16 {
+            let x = generate_random_neutrosophic(&mut rng, 256);
+            assert_eq!(g.pow_mod_with_order(&x, &p, &order), g.pow_mod(&x, &p));
+        }
+    }
+
+    /// Computes `g^x mod p` from the ring operations alone, for auditing `pow_mod`.
+    ///
+    /// `g^x1` and `g^x2` are formed by repeated `Mul`. Raising to `x2*I` uses the
+    /// idempotent rule `f(tI) = f(0) + (f(t) - f(0))I`, so `g^(x2*I)` is
+    /// `1 + ((g^x2)_total - 1)I`. Only suitable for small non-negative exponents.
+    fn pow_mod_reference(
+        g: &NeutrosophicNumber,
+        exp: &NeutrosophicNumber,
+        modulus: &NeutrosophicNumber,
+    ) -> NeutrosophicNumber {
+        let repeated_mul = |n: &BigInt| {
+            let mut acc = NeutrosophicNumber::one();
+            let mut k = BigInt::zero();
+            while &k < n {
+                acc = acc.mul_mod(g, modulus);
+                k += 1;
+            }
+            acc
+        };
+        let real_power = repeated_mul(&exp.a);
+        let i_power = repeated_mul(&exp.b);
+        let lifted = NeutrosophicNumber::new(BigInt::one(), i_power.indeterminate_total() - 1);
+        real_power.mul_mod(&lifted, modulus)
+    }
+
+    #[test]
+    fn test_neutrosophic_pow_mod_matches_reference() {
+        use rand::Rng;
+
+        let mut rng = StdRng::seed_from_u64(107);
+        for p in [(7, 4), (101, 2), (13, -2)] {
+            let p = NeutrosophicNumber::from(p);
+            for _ in 0..64 {
+                let g = NeutrosophicNumber::from((rng.gen_range(-50..50), rng.gen_range(-50..50)));
+                let x = NeutrosophicNumber::from((rng.gen_range(0..20), rng.gen_range(0..20)));
+                assert_eq!(
+                    g.pow_mod(&x, &p),
+                    pow_mod_reference(&g, &x, &p),
+                    "g = {}, x = {}, p = {}",
+                    g,
+                    x,
+                    p
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_neutrosophic_pow_mod_real_fast_path() {
+        let mut rng = StdRng::seed_from_u64(113);
+        let p = NeutrosophicNumber::from(generate_random_neutrosophic_exact(&mut rng, 256).a);
+        for _ in 0..8 {
+            let g = NeutrosophicNumber::from(generate_random_neutrosophic(&mut rng, 256).a);
+            let x = NeutrosophicNumber::from(generate_random_neutrosophic(&mut rng, 256).a);
+            // `pow_mod_split` always takes the general two-exponentiation path.
+            let general = pow_mod_split(&g.a, &g.indeterminate_total(), &p.a, &p.a, &x);
+            assert_eq!(g.pow_mod(&x, &p), general);
+            assert!(g.pow_mod(&x, &p).b.is_zero());
+        }
+    }
+
+    #[test]
+    fn test_neutrosophic_gcd_lcm_real() {
+        let x = NeutrosophicNumber::from((12, 5));
+        let y = NeutrosophicNumber::from((18, -7));
+        assert_eq!(x.gcd_real(&y), BigInt::from(6));
+        assert_eq!(x.lcm_real(&y), BigInt::from(36));
+
+        // Signs are ignored, and coprime real parts have gcd 1.
+        let z = NeutrosophicNumber::from((-35, 1));
+        assert_eq!(x.gcd_real(&z), BigInt::from(1));
+        assert_eq!(x.lcm_real(&z), BigInt::from(420));
+        assert_eq!(
+            x.lcm_real(&NeutrosophicNumber::from((0, 3))),
+            BigInt::from(0)
+        );
+    }
+
+    #[test]
+    fn test_neutrosophic_to_matrix_respects_mul() {
+        let n1 = NeutrosophicNumber::from((1, 2));
+        let n2 = NeutrosophicNumber::from((3, -4));
+        assert_eq!(
+            n1.to_matrix(),
+            [
+                [BigInt::from(1), BigInt::from(0)],
+                [BigInt::from(2), BigInt::from(3)]
+            ]
+        );
+
+        let (m1, m2) = (n1.to_matrix(), n2.to_matrix());
+        let mut product: [[BigInt; 2]; 2] = Default::default();
+        for (i, row) in product.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                *entry = &m1[i][0] * &m2[0][j] + &m1[i][1] * &m2[1][j];
+            }
+        }
+        assert_eq!(product, (n1 * n2).to_matrix());
+    }
+
+    #[test]
+    #[should_panic(expected = "neutrosophic modulus sum p1+p2 must be nonzero")]
+    fn test_neutrosophic_pow_mod_zero_modulus_sum_panics() {
+        let g = NeutrosophicNumber::from((3, 2));
+        let x = NeutrosophicNumber::from((4, 1));
+        let p = NeutrosophicNumber::from((5, -5));
+        g.pow_mod(&x, &p);
+    }
+
+    #[test]
+    #[should_panic(expected = "neutrosophic modulus sum p1+p2 must be nonzero")]
+    fn test_neutrosophic_pow_mod_scalar_zero_modulus_sum_panics() {
+        let g = NeutrosophicNumber::from((3, 2));
+        let p = NeutrosophicNumber::from((5, -5));
+        g.pow_mod_scalar(&BigInt::from(4), &p);
+    }
+
+    #[test]
+    fn test_every_pow_mod_variant_rejects_zero_modulus_sum() {
+        use std::panic::catch_unwind;
+
+        let g = NeutrosophicNumber::from((3, 2));
+        let x = NeutrosophicNumber::from((4, 1));
+        let p = NeutrosophicNumber::from((5, -5));
+        let variants: [Box<dyn Fn()>; 6] = [
+            Box::new(|| drop(g.pow_mod_projections(&x, &p))),
+            Box::new(|| drop(g.pow_mod_u64(4, &p))),
+            Box::new(|| drop(g.pow_mod_windowed(&x, &p, 4))),
+            Box::new(|| drop(g.pow_mod_canonical(&x, &p))),
+            Box::new(|| drop(g.precompute(&p).pow(&x))),
+            Box::new(|| drop(g.pow_mod_batch(std::slice::from_ref(&x), &p))),
+        ];
+        for variant in variants {
+            let payload = catch_unwind(std::panic::AssertUnwindSafe(variant)).unwrap_err();
+            let message = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str));
+            assert_eq!(
+                message,
+                Some("neutrosophic modulus sum p1+p2 must be nonzero")
+            );
+        }
+    }
+
+    #[test]
+    fn test_find_generator_small_prime() {
+        // 3 is the smallest primitive root mod 7 and 2 the smallest mod 11.
+        let p = NeutrosophicNumber::from((7, 4));
+        let g = find_generator(&p).unwrap();
+        assert_eq!(g, NeutrosophicNumber::from((3, -1)));
+        assert!(g.is_reduced(&p));
+
+        // The real part has order exactly p1 - 1, and the sum order p1+p2 - 1.
+        let order_of = |base: &BigInt, modulus: &BigInt| {
+            (1u32..)
+                .find(|&k| base.modpow(&BigInt::from(k), modulus).is_one())
+                .unwrap()
+        };
+        assert_eq!(order_of(&g.a, &p.a), 6);
+        assert_eq!(order_of(&g.indeterminate_total(), &BigInt::from(11)), 10);
+
+        assert_eq!(find_generator(&NeutrosophicNumber::from((9, 2))), None);
+    }
+
+    #[test]
+    fn test_neutrosophic_new_modulus() {
+        let p = NeutrosophicNumber::new_modulus(BigInt::from(7), BigInt::from(4)).unwrap();
+        assert_eq!(p, NeutrosophicNumber::from((7, 4)));
+        // A negative coefficient is fine as long as the sum stays positive.
+        assert!(NeutrosophicNumber::new_modulus(BigInt::from(7), BigInt::from(-6)).is_ok());
+
+        assert_eq!(
+            NeutrosophicNumber::new_modulus(BigInt::from(5), BigInt::from(-5)),
+            Err(NeutrosophicError::NonPositiveModulus)
+        );
+        assert_eq!(
+            NeutrosophicNumber::new_modulus(BigInt::from(5), BigInt::from(-9)),
+            Err(NeutrosophicError::NonPositiveModulus)
+        );
+        assert_eq!(
+            NeutrosophicNumber::new_modulus(BigInt::from(0), BigInt::from(7)),
+            Err(NeutrosophicError::NonPositiveModulus)
+        );
+    }
+
+    #[test]
+    fn test_neutrosophic_multiplicative_order() {
+        // Modulo 7 + 4I, the real part 3 has order 6 mod 7 and the sum 5 has order 5
+        // mod 11, so 3 + 2I has order lcm(6, 5) = 30.
+        let g = NeutrosophicNumber::from((3, 2));
+        let p = NeutrosophicNumber::from((7, 4));
+        assert_eq!(
+            g.multiplicative_order(&p, &BigInt::from(100)),
+            Some(BigInt::from(30))
+        );
+        assert_eq!(
+            g.pow_mod_scalar(&BigInt::from(30), &p),
+            NeutrosophicNumber::one()
+        );
+        assert_eq!(g.multiplicative_order(&p, &BigInt::from(29)), None);
+
+        // The generator found for the same modulus has order lcm(6, 10) = 30 as well.
+        let generator = find_generator(&p).unwrap();
+        assert_eq!(
+            generator.multiplicative_order(&p, &BigInt::from(100)),
+            Some(BigInt::from(30))
+        );
+
+        // 7 is not invertible modulo 7, so no power of it is the identity.
+        let zero_divisor = NeutrosophicNumber::from((7, 0));
+        assert_eq!(
+            zero_divisor.multiplicative_order(&p, &BigInt::from(100)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_neutrosophic_pow_mod_projections_match_pow_mod() {
+        let mut rng = StdRng::seed_from_u64(114);
+        for _ in 0..20 {
+            let g = generate_random_neutrosophic(&mut rng, 64);
+            let x = generate_random_neutrosophic(&mut rng, 64);
+            let p = generate_random_neutrosophic_exact(&mut rng, 64);
+            let out = g.pow_mod(&x, &p);
+            let (real, total) = g.pow_mod_projections(&x, &p);
+            assert_eq!(real, out.a);
+            assert_eq!(total, &out.a + &out.b);
+        }
+
+        let g = NeutrosophicNumber::from((3, 2));
+        let x = NeutrosophicNumber::from((4, 1));
+        let p = NeutrosophicNumber::from((7, 4));
+        assert_eq!(
+            g.pow_mod_projections(&x, &p),
+            (BigInt::from(4), BigInt::from(1))
+        );
+    }
+
+    #[test]
+    fn test_neutrosophic_inherent_identities() {
+        let mut rng = StdRng::seed_from_u64(116);
+        let n = generate_random_neutrosophic(&mut rng, 128);
+        assert_eq!(NeutrosophicNumber::one() * n.clone(), n);
+        assert_eq!(NeutrosophicNumber::zero() + n.clone(), n);
+        assert_eq!(
+            NeutrosophicNumber::zero(),
+            <NeutrosophicNumber as Zero>::zero()
+        );
+        assert_eq!(
+            NeutrosophicNumber::one(),
+            <NeutrosophicNumber as One>::one()
+        );
+    }
+
+    #[test]
+    fn test_checked_generate_random_neutrosophic_bounds() {
+        let mut rng = StdRng::seed_from_u64(117);
+        assert!(matches!(
+            checked_generate_random_neutrosophic(&mut rng, 0),
+            Err(NeutrosophicError::OutOfRange(_))
+        ));
+        assert!(matches!(
+            checked_generate_random_neutrosophic(&mut rng, MAX_BIT_SIZE + 1),
+            Err(NeutrosophicError::OutOfRange(_))
+        ));
+
+        let n = checked_generate_random_neutrosophic(&mut rng, MAX_BIT_SIZE).unwrap();
+        assert!(n.is_positive());
+        assert!(n.bit_length() <= MAX_BIT_SIZE as u64);
+        let n = checked_generate_random_neutrosophic(&mut rng, 1).unwrap();
+        assert_eq!(n, NeutrosophicNumber::from((1, 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "random components are limited to MAX_BIT_SIZE bits")]
+    fn test_generate_random_neutrosophic_rejects_huge_sizes() {
+        generate_random_neutrosophic(&mut StdRng::seed_from_u64(118), MAX_BIT_SIZE + 1);
+    }
+
+    #[test]
+    fn test_neutrosophic_pow_mod_assign_matches_pow_mod() {
+        let mut rng = StdRng::seed_from_u64(119);
+        for _ in 0..10 {
+            let base = generate_random_neutrosophic(&mut rng, 64);
+            let exp = generate_random_neutrosophic(&mut rng, 64);
+            let p = generate_random_neutrosophic_exact(&mut rng, 64);
+            let mut n = base.clone();
+            n.pow_mod_assign(&exp, &p);
+            assert_eq!(n, base.pow_mod(&exp, &p));
+        }
+
+        // Pure-real inputs take `pow_mod`'s fast path; the in-place result agrees.
+        let mut n = neu(2, 0);
+        n.pow_mod_assign(&neu(3, 0), &neu(5, 0));
+        assert_eq!(n, neu(3, 0));
+    }
+
+    #[test]
+    fn test_neutrosophic_hex_round_trip() {
+        assert_eq!(neu(31, 42).to_hex(), "+1f:+2a");
+        assert_eq!(neu(0, -42).to_hex(), "+0:-2a");
+
+        let mut rng = StdRng::seed_from_u64(120);
+        let large = generate_random_neutrosophic(&mut rng, 256);
+        for n in [
+            neu(31, 42),
+            neu(0, -42),
+            neu(-255, 0),
+            -large.clone(),
+            large,
+        ] {
+            assert_eq!(NeutrosophicNumber::from_hex(&n.to_hex()).unwrap(), n);
+        }
+        assert_eq!(
+            NeutrosophicNumber::from_hex("+1F:-2A").unwrap(),
+            neu(31, -42)
+        );
+
+        for bad in [
+            "",
+            "+1f",
+            "1f:+2a",
+            "+1f:2a",
+            "+:+2a",
+            "+1f:+2a:+3",
+            "+1f:+-2a",
+            "+1g:+2a",
+        ] {
+            assert!(
+                matches!(
+                    NeutrosophicNumber::from_hex(bad),
+                    Err(NeutrosophicError::ParseError(_))
+                ),
+                "{:?} should not parse",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn test_neutrosophic_is_identity_mod() {
+        let p = neu(7, 4);
+        assert!(NeutrosophicNumber::one().is_identity_mod(&p));
+        // 8 ≡ 1 (mod 7) and 8 + 4 = 12 ≡ 1 (mod 11).
+        let unreduced = neu(8, 4);
+        assert_ne!(unreduced, NeutrosophicNumber::one());
+        assert!(unreduced.is_identity_mod(&p));
+        assert!(neu(-6, -4).is_identity_mod(&p));
+
+        assert!(!neu(1, 1).is_identity_mod(&p));
+        assert!(!NeutrosophicNumber::zero().is_identity_mod(&p));
+        let g = neu(3, 2);
+        assert!(g.pow_mod_scalar(&BigInt::from(30), &p).is_identity_mod(&p));
+    }
+
+    #[test]
+    fn test_neutrosophic_pow_mod_canonical_is_bitwise_stable() {
+        let p = neu(7, 4);
+        let g = neu(3, 2);
+        let x = neu(4, 1);
+        // pow_mod returns 4 + (-3)I here; the canonical coefficient is -3 mod 11 = 8.
+        assert_eq!(g.pow_mod(&x, &p), neu(4, -3));
+        let expected = g.pow_mod_canonical(&x, &p);
+        assert_eq!(expected, neu(4, 8));
+
+        // Congruent bases and exponents encode to the same bytes.
+        let bases = [g.clone(), g.clone() + p.clone(), g.clone() + neu(14, -14)];
+        let exps = [x.clone(), x.clone() + neu(6, 4), x + neu(12, 18)];
+        for base in &bases {
+            for exp in &exps {
+                let canonical = base.pow_mod_canonical(exp, &p);
+                assert!(canonical.congruent_mod(&expected, &p));
+                assert_eq!(canonical.to_bytes_be(), expected.to_bytes_be());
+            }
+        }
+    }
+
+    #[test]
+    fn test_neutrosophic_pure_real_dispatch() {
+        let mut rng = StdRng::seed_from_u64(121);
+        let real_only = |n: NeutrosophicNumber| NeutrosophicNumber::new(n.a, BigInt::zero());
+        for _ in 0..10 {
+            let base = real_only(generate_random_neutrosophic(&mut rng, 128));
+            let exp = real_only(generate_random_neutrosophic(&mut rng, 128));
+            let p = real_only(generate_random_neutrosophic_exact(&mut rng, 128));
+            assert!(is_pure_real(&base, &exp, &p));
+
+            // Every entry point agrees with the general formula evaluated by hand.
+            let general = pow_mod_split(&base.a, &base.a, &p.a, &p.a, &exp);
+            assert_eq!(base.pow_mod(&exp, &p), general);
+            let (real, total) = base.pow_mod_projections(&exp, &p);
+            assert_eq!((real, total), (general.a.clone(), general.a.clone()));
+            assert_eq!(base.pow_mod_scalar(&exp.a, &p), general);
+            assert_eq!(base.pow_mod_windowed(&exp, &p, 4), general);
+
+            for small in [0, 1, 65537, u64::MAX] {
+                let exp = NeutrosophicNumber::new(small.into(), BigInt::zero());
+                let general = pow_mod_split(&base.a, &base.a, &p.a, &p.a, &exp);
+                assert_eq!(base.pow_mod_u64(small, &p), general);
+            }
+        }
+        assert!(!is_pure_real(&neu(2, 0), &neu(3, 0), &neu(5, 1)));
+        assert!(!is_pure_real_scalar(&neu(2, 1), &neu(5, 0)));
+    }
+
+    #[test]
+    fn test_neutrosophic_pow_mod_u64() {
+        let p = neu(7, 4);
+        let g = neu(3, 2);
+        assert_eq!(g.pow_mod_u64(3, &p), g.pow_mod_scalar(&3.into(), &p));
+        assert_eq!(neu(2, 0).pow_mod_u64(3, &neu(5, 0)), neu(3, 0));
+
+        let mut rng = StdRng::seed_from_u64(122);
+        for exp in [0, 1, 2, 65537, u64::MAX] {
+            let base = generate_random_neutrosophic(&mut rng, 256);
+            let p = generate_random_neutrosophic_exact(&mut rng, 256);
+            assert_eq!(
+                base.pow_mod_u64(exp, &p),
+                base.pow_mod_scalar(&exp.into(), &p)
+            );
+            assert_eq!(
+                (-base.clone()).pow_mod_u64(exp, &p),
+                (-base).pow_mod_scalar(&exp.into(), &p)
+            );
+        }
+        // Negative and unit moduli follow `BigInt::modpow`.
+        for (base, exp, modulus) in [(3, 3, -7), (-3, 3, -7), (4, 5, 1), (0, 0, 7)] {
+            let (base, modulus) = (BigInt::from(base), BigInt::from(modulus));
+            assert_eq!(
+                modpow_u64(&base, exp, &modulus),
+                base.modpow(&BigInt::from(exp), &modulus)
+            );
+        }
+    }
+
+    #[test]
+    fn test_neutrosophic_i128_parts() {
+        for (a, b) in [
+            (0, 0),
+            (3, -1),
+            (i128::MAX, i128::MIN),
+            (-(1 << 100), 1 << 90),
+        ] {
+            let n = NeutrosophicNumber::from_i128_parts(a, b);
+            assert_eq!(n.to_i128_parts(), Some((a, b)));
+        }
+        assert_eq!(neu(3, -1).to_i128_parts(), Some((3, -1)));
+
+        let too_big = NeutrosophicNumber::new(BigInt::from(i128::MAX) + 1, BigInt::from(1));
+        assert_eq!(too_big.to_i128_parts(), None);
+        let too_small = NeutrosophicNumber::new(BigInt::from(1), BigInt::from(i128::MIN) - 1);
+        assert_eq!(too_small.to_i128_parts(), None);
+    }
+
+    #[test]
+    fn test_neutrosophic_biguint_parts() {
+        assert_eq!(
+            neu(3, 2).to_biguint_parts(),
+            Some((BigUint::from(3u32), BigUint::from(2u32)))
+        );
+        assert_eq!(
+            NeutrosophicNumber::zero().to_biguint_parts(),
+            Some((BigUint::zero(), BigUint::zero()))
+        );
+
+        // 3^3 mod 7 = 6 and 5^3 mod 11 = 4, so the coefficient is 4 - 6 = -2.
+        let r = neu(3, 2).pow_mod(&neu(3, 0), &neu(7, 4));
+        assert_eq!(r, neu(6, -2));
+        assert_eq!(r.to_biguint_parts(), None);
+        assert_eq!(neu(-1, 2).to_biguint_parts(), None);
+    }
+
+    #[test]
+    fn test_neutrosophic_builder_reduces() {
+        assert_eq!(
+            NeutrosophicBuilder::new().build(),
+            NeutrosophicNumber::zero()
+        );
+        assert_eq!(NeutrosophicBuilder::new().a(3).b(-1).build(), neu(3, -1));
+
+        let mut rng = StdRng::seed_from_u64(126);
+        let large = generate_random_neutrosophic(&mut rng, 512);
+        let p = generate_neutrosophic_prime(&mut rng, 64, 12);
+        let built = NeutrosophicBuilder::new()
+            .a(large.a.clone())
+            .b(-large.b.clone())
+            .modulus(p.clone())
+            .build();
+        let expected = NeutrosophicNumber::new(large.a, -large.b).normalize_mod(&p);
+        assert_eq!(built, expected);
+        assert!(built.is_reduced(&p));
+    }
+
+    #[test]
+    fn test_neutrosophic_square_chain() {
+        // Modulo 7 + 4I the real part runs 3, 2, 4, 2 (mod 7) and the sum 5, 3, 9, 4 (mod 11).
+        let g = neu(3, 2);
+        let p = neu(7, 4);
+        let chain: Vec<NeutrosophicNumber> = g.square_chain(&p).take(4).collect();
+        assert_eq!(chain, [neu(3, 2), neu(2, 1), neu(4, 5), neu(2, 2)]);
+        for (k, square) in chain.iter().enumerate() {
+            assert!(square.congruent_mod(&g.pow_mod_u64(1 << k, &p), &p));
+        }
+
+        // An unreduced base starts the chain at its reduced form.
+        let first = neu(10, 5).square_chain(&p).next();
+        assert_eq!(first, Some(neu(3, 1)));
+    }
+
+    #[test]
+    fn test_neutrosophic_from_unsigned() {
+        let five = NeutrosophicNumber::from(5u64);
+        assert_eq!(five, NeutrosophicNumber::new(5.into(), 0.into()));
+        assert_eq!(NeutrosophicNumber::from(5u32), five);
+        assert_eq!(NeutrosophicNumber::from(u64::MAX).a, BigInt::from(u64::MAX));
+
+        // Pure-real constants compose with the operators like any other value.
+        let n = neu(3, 2);
+        assert_eq!(n.clone() + NeutrosophicNumber::from(5u64), neu(8, 2));
+        assert_eq!(n.clone() * NeutrosophicNumber::from(5u32), neu(15, 10));
+        assert_eq!(
+            NeutrosophicNumber::from(2u64).pow_mod(&3u64.into(), &5u64.into()),
+            NeutrosophicNumber::from(3u64)
+        );
+    }
+
+    #[test]
+    fn test_neutrosophic_indeterminate_sign() {
+        // 2^3 (mod 5) + I * [3^3 (mod 5) - 2^3 (mod 5)] = 3 + (2 - 3)I.
+        let output = neu(2, 1).pow_mod(&neu(3, 0), &neu(5, 0));
+        assert_eq!(output, neu(3, -1));
+        assert_eq!(output.indeterminate_sign(), CmpOrdering::Less);
+        assert_eq!(neu(3, 0).indeterminate_sign(), CmpOrdering::Equal);
+        assert_eq!(neu(-3, 4).indeterminate_sign(), CmpOrdering::Greater);
+    }
+
+    #[test]
+    fn test_multiplication_under_each_rule() {
+        let dual = |a: i64, b: i64| DualNumber::with_rule(BigInt::from(a), BigInt::from(b));
+
+        // (3 + 2I)(5 + 4I) = 15 + (12 + 10)I + 8I^2.
+        assert_eq!(neu(3, 2) * neu(5, 4), neu(15, 30));
+        assert_eq!(dual(3, 2) * dual(5, 4), dual(15, 22));
+        assert_eq!(&dual(3, 2) * &dual(5, 4), dual(15, 22));
+
+        // I is idempotent under one rule and nilpotent under the other.
+        assert_eq!(neu(0, 1) * neu(0, 1), neu(0, 1));
+        assert_eq!(dual(0, 1) * dual(0, 1), dual(0, 0));
+
+        // Both rules agree whenever one factor is pure real.
+        assert_eq!(neu(7, 0) * neu(-2, 3), neu(-14, 21));
+        assert_eq!(dual(7, 0) * dual(-2, 3), dual(-14, 21));
+
+        // Dual numbers carry derivatives: (x + I)^2 = x^2 + 2x*I.
+        let x = dual(6, 1);
+        assert_eq!(&x * &x, dual(36, 12));
+        assert_eq!(x.clone() + dual(1, 1) - dual(2, 0), dual(5, 2));
+        assert_eq!(-x, dual(-6, -1));
+        assert_eq!(format!("{:?}", dual(1, 2)), format!("{:?}", neu(1, 2)));
+    }
+
+    #[test]
+    fn test_hamming_weight_of_small_exponents() {
+        // 0b101 and 0b1100 have two bits each; 0b1111_1111 has eight.
+        assert_eq!(hamming_weight(&neu(5, 12)), 4);
+        assert_eq!(hamming_weight(&neu(255, 0)), 8);
+        assert_eq!(hamming_weight(&neu(0, 0)), 0);
+        assert_eq!(hamming_weight(&neu(1, 1)), 2);
+        // Signs are ignored: |-6| = 0b110.
+        assert_eq!(hamming_weight(&neu(-6, 6)), 4);
+        let power_of_two = NeutrosophicNumber::new(BigInt::one() << 200, BigInt::zero());
+        assert_eq!(hamming_weight(&power_of_two), 1);
+        assert_eq!(hamming_weight(&NeutrosophicNumber::from(u64::MAX)), 64);
+    }
 }
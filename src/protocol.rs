@@ -0,0 +1,1347 @@
+//! The Neutrosophic 1-Round ZKP protocol between Peggy (the prover) and Victor
+//! (the verifier), plus its Fiat-Shamir variant.
+//!
+//! Each step is logged through the `log` facade: challenges and responses at
+//! `debug` level and verification results at `info` level. Secrets are never logged.
+//!
+//! WARNING: The protocol is NOT SECURE; see the crate README for the analysis.
+
+use crate::error::{NeutrosophicError, VerifyFailure};
+use crate::keys::{PublicKey, SecretKey};
+use crate::neutrosophic_numbers::{NeutrosophicNumber, generate_random_neutrosophic};
+use crate::secret::Secret;
+use log::{debug, info};
+use num_bigint::{BigInt, RandBigInt, Sign};
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+/// Bit size of Victor's per-session secret `y`.
+/// In a real scenario, the bit size should match the security level.
+pub const CHALLENGE_BIT_SIZE: usize = 2048;
+
+/// Peggy, the prover, who holds the secret `x`.
+pub struct Prover {
+    /// Peggy's secret key.
+    pub x: Secret,
+}
+
+impl Prover {
+    /// Creates Peggy from her typed secret key.
+    ///
+    /// # Arguments
+    /// * `key` - Peggy's secret key `x`.
+    pub fn new(key: SecretKey) -> Self {
+        Prover {
+            x: key.into_secret(),
+        }
+    }
+
+    /// Computes the response `r = c^x mod p` to Victor's challenge using the secret `x`.
+    ///
+    /// # Arguments
+    /// * `challenge` - The challenge `c = g^y mod p` received from Victor.
+    /// * `p` - The public neutrosophic modulus.
+    pub fn respond(
+        &self,
+        challenge: &NeutrosophicNumber,
+        p: &NeutrosophicNumber,
+    ) -> NeutrosophicNumber {
+        let r = challenge.pow_mod(self.x.expose(), p);
+        debug!("response computed: r = {:.32}", r);
+        r
+    }
+}
+
+/// Victor, the verifier, who knows only the public parameters.
+pub struct Verifier {
+    /// The public generator of the group.
+    pub g: NeutrosophicNumber,
+    /// The public neutrosophic modulus (prime).
+    pub p: NeutrosophicNumber,
+    /// Peggy's public value `g^x mod p`.
+    pub b: NeutrosophicNumber,
+}
+
+impl Verifier {
+    /// Creates Victor from the public parameters and Peggy's typed public key.
+    ///
+    /// # Arguments
+    /// * `g` - The public generator of the group.
+    /// * `p` - The public neutrosophic modulus.
+    /// * `public_key` - Peggy's public key `b = g^x mod p`.
+    pub fn new(g: NeutrosophicNumber, p: NeutrosophicNumber, public_key: PublicKey) -> Self {
+        Verifier {
+            g,
+            p,
+            b: public_key.0,
+        }
+    }
+
+    /// Creates Victor from validated group parameters and Peggy's typed public key.
+    ///
+    /// # Arguments
+    /// * `group` - The generator `g` and modulus `p`.
+    /// * `public_key` - Peggy's public key `b = g^x mod p`.
+    pub fn from_group(group: GroupParams, public_key: PublicKey) -> Self {
+        Verifier::new(group.g, group.p, public_key)
+    }
+
+    /// Generates a random secret `y` and the challenge `c = g^y mod p`.
+    ///
+    /// # Returns
+    /// The pair `(c, y)`. `c` is sent to Peggy, while `y` must be kept secret
+    /// until the response is verified.
+    pub fn challenge<R: Rng + RandBigInt>(&self, rng: &mut R) -> (NeutrosophicNumber, Secret) {
+        let y = Secret::new(generate_random_neutrosophic(rng, CHALLENGE_BIT_SIZE));
+        let c = self.g.pow_mod(y.expose(), &self.p);
+        debug!("challenge generated: c = {:.32}", c);
+        (c, y)
+    }
+
+    /// Generates a challenge bound to a nonce, so that it cannot be replayed.
+    ///
+    /// Victor draws a random `y0` as in `challenge` and blinds it additively with the
+    /// nonce: `y = y0 + H(nonce)`, where each component of `H(nonce)` is the SHA-256
+    /// digest of a domain tag, the component index, and `nonce`. Peggy answers
+    /// `c = g^y mod p` with `respond` as usual, and the response only passes
+    /// `verify_with_nonce` under the same nonce.
+    ///
+    /// # Arguments
+    /// * `nonce` - A value unique to this session, e.g. a counter or timestamp.
+    /// * `rng` - The random number generator `y0` is drawn from.
+    ///
+    /// # Returns
+    /// The pair `(c, y0)`. `y0` must be kept secret and passed to `verify_with_nonce`.
+    pub fn challenge_with_nonce<R: Rng + RandBigInt>(
+        &self,
+        nonce: &[u8],
+        rng: &mut R,
+    ) -> (NeutrosophicNumber, Secret) {
+        let y0 = Secret::new(generate_random_neutrosophic(rng, CHALLENGE_BIT_SIZE));
+        let y = Secret::new(y0.expose() + &nonce_exponent(nonce));
+        let c = self.g.pow_mod(y.expose(), &self.p);
+        debug!("challenge generated: c = {:.32}", c);
+        (c, y0)
+    }
+
+    /// Checks a response to `challenge_with_nonce` under the given nonce.
+    ///
+    /// # Arguments
+    /// * `nonce` - The nonce the challenge was generated with.
+    /// * `y0` - The secret returned alongside the challenge.
+    /// * `r` - Peggy's response to that challenge.
+    ///
+    /// # Returns
+    /// `true` if `r` equals `b^(y0 + H(nonce)) mod p`, `false` otherwise.
+    pub fn verify_with_nonce(
+        &self,
+        nonce: &[u8],
+        y0: &NeutrosophicNumber,
+        r: &NeutrosophicNumber,
+    ) -> bool {
+        let y = Secret::new(y0 + &nonce_exponent(nonce));
+        self.verify(y.expose(), r)
+    }
+
+    /// Checks Peggy's response `r` against the verification value `r' = b^y mod p`.
+    ///
+    /// # Arguments
+    /// * `y` - The secret returned alongside the challenge by `challenge`.
+    /// * `r` - Peggy's response to that challenge.
+    ///
+    /// A response with more bits than any reduced value could have (see
+    /// `response_too_large`) is rejected before anything is computed from it.
+    ///
+    /// # Returns
+    /// `true` if the response matches, `false` otherwise.
+    pub fn verify(&self, y: &NeutrosophicNumber, r: &NeutrosophicNumber) -> bool {
+        self.verify_against(&self.b, y, r)
+    }
+
+    /// Checks many independent responses, each against its own public key.
+    ///
+    /// Every item is a triple `(b_i, r_i, y_i)` of a prover's public key, her response,
+    /// and the secret `y_i` behind the challenge she answered. Each is checked exactly
+    /// like `verify` with `b_i` in place of `self.b`, under the shared modulus `p`.
+    ///
+    /// With the `rayon` feature enabled, the items are checked in parallel.
+    ///
+    /// # Arguments
+    /// * `items` - The `(b_i, r_i, y_i)` triples to check.
+    ///
+    /// # Returns
+    /// One result per item, in order.
+    pub fn verify_batch(
+        &self,
+        items: &[(NeutrosophicNumber, NeutrosophicNumber, NeutrosophicNumber)],
+    ) -> Vec<bool> {
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+
+            items
+                .par_iter()
+                .map(|(b, r, y)| self.verify_against(b, y, r))
+                .collect()
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            items
+                .iter()
+                .map(|(b, r, y)| self.verify_against(b, y, r))
+                .collect()
+        }
+    }
+
+    /// The check behind `verify`, against an arbitrary public key `b`.
+    fn verify_against(
+        &self,
+        b: &NeutrosophicNumber,
+        y: &NeutrosophicNumber,
+        r: &NeutrosophicNumber,
+    ) -> bool {
+        if self.response_too_large(r) {
+            log_verification(false);
+            return false;
+        }
+        let r_victor = b.pow_mod(y, &self.p);
+        // The comparison involves secret-derived values, so it must not leak timing.
+        let verified = r.ct_eq(&r_victor);
+        log_verification(verified);
+        verified
+    }
+
+    /// Checks Peggy's response like `verify`, but reports why a response was rejected.
+    ///
+    /// The public parameters are checked with `validate_params` first, then the
+    /// response must pass the size check of `verify` and be reduced modulo `p` (see
+    /// `NeutrosophicNumber::is_reduced`), and finally it is compared against
+    /// `b^y mod p` in constant time.
+    ///
+    /// # Arguments
+    /// * `y` - The secret returned alongside the challenge by `challenge`.
+    /// * `r` - Peggy's response to that challenge.
+    ///
+    /// # Errors
+    /// * `VerifyFailure::InvalidParams` if `g`, `p`, or `b` is invalid.
+    /// * `VerifyFailure::ResponseOutOfRange` if `r` is oversized or not reduced modulo `p`.
+    /// * `VerifyFailure::Mismatch` if `r` differs from `b^y mod p`.
+    pub fn verify_detailed(
+        &self,
+        y: &NeutrosophicNumber,
+        r: &NeutrosophicNumber,
+    ) -> Result<(), VerifyFailure> {
+        validate_params(&self.g, &self.p, &self.b).map_err(VerifyFailure::InvalidParams)?;
+        if self.response_too_large(r) || !r.is_reduced(&self.p) {
+            return Err(VerifyFailure::ResponseOutOfRange);
+        }
+        if !self.verify(y, r) {
+            return Err(VerifyFailure::Mismatch);
+        }
+        Ok(())
+    }
+
+    /// Checks whether `r` has more bits than any value reduced modulo `p`.
+    ///
+    /// A reduced response has `0 <= a < p1` and `-p1 < b < p1+p2`, and `p1+p2` has at
+    /// most one bit more than `p.bit_length()`. Comparing bit lengths costs constant
+    /// time, so a prover cannot make Victor walk an astronomically large number.
+    fn response_too_large(&self, r: &NeutrosophicNumber) -> bool {
+        r.bit_length() > self.p.bit_length() + 1
+    }
+
+    /// Computes the verification value `r' = b^y mod p` that Peggy's response must match.
+    ///
+    /// # Arguments
+    /// * `y` - The secret returned alongside the challenge by `challenge`.
+    pub fn verification_value(&self, y: &NeutrosophicNumber) -> NeutrosophicNumber {
+        self.b.pow_mod(y, &self.p)
+    }
+}
+
+/// Logs the outcome of a verification at `info` level.
+fn log_verification(verified: bool) {
+    if verified {
+        info!("verification succeeded");
+    } else {
+        info!("verification failed");
+    }
+}
+
+/// Format version written into new `ProtocolParams`.
+pub const PROTOCOL_PARAMS_VERSION: &str = "1";
+
+/// The public parameters of a protocol setup, for sharing between Peggy and Victor.
+///
+/// With the `serde` feature enabled, this serializes to a single object
+/// `{"version": .., "g": .., "p": .., "b": ..}` with each number encoded as in
+/// `NeutrosophicNumber`. The `version` is carried through unchanged, so a reader can
+/// detect a format it does not understand.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProtocolParams {
+    /// The format version, `PROTOCOL_PARAMS_VERSION` when created by `new`.
+    pub version: String,
+    /// The public generator of the group.
+    pub g: NeutrosophicNumber,
+    /// The public neutrosophic modulus.
+    pub p: NeutrosophicNumber,
+    /// Peggy's public value `g^x mod p`.
+    pub b: NeutrosophicNumber,
+}
+
+impl ProtocolParams {
+    /// Bundles the public parameters under the current format version.
+    ///
+    /// # Arguments
+    /// * `g` - The public generator of the group.
+    /// * `p` - The public neutrosophic modulus.
+    /// * `b` - Peggy's public value `g^x mod p`.
+    pub fn new(g: NeutrosophicNumber, p: NeutrosophicNumber, b: NeutrosophicNumber) -> Self {
+        ProtocolParams {
+            version: PROTOCOL_PARAMS_VERSION.to_string(),
+            g,
+            p,
+            b,
+        }
+    }
+}
+
+/// The record of a single protocol round, for debugging failed verifications.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Transcript {
+    /// Victor's challenge `c = g^y mod p`.
+    pub challenge: NeutrosophicNumber,
+    /// Peggy's response `r = c^x mod p`.
+    pub prover_response: NeutrosophicNumber,
+    /// Victor's verification value `r' = b^y mod p`.
+    pub verifier_value: NeutrosophicNumber,
+    /// Whether the response matched the verification value.
+    pub verified: bool,
+}
+
+/// Runs a single protocol round and records every exchanged value.
+///
+/// This performs the same steps as `run_rounds` with one round, but returns a
+/// `Transcript` instead of a bare `bool` so the individual values can be
+/// inspected or printed. The recorded decision comes from `Verifier::verify`.
+///
+/// # Arguments
+/// * `prover` - Peggy, holding the secret `x`.
+/// * `verifier` - Victor, holding the public parameters.
+/// * `rng` - The random number generator Victor draws `y` from.
+pub fn run_with_transcript<R: Rng + RandBigInt>(
+    prover: &Prover,
+    verifier: &Verifier,
+    rng: &mut R,
+) -> Transcript {
+    let (challenge, y) = verifier.challenge(rng);
+    let prover_response = prover.respond(&challenge, &verifier.p);
+    let verifier_value = verifier.verification_value(y.expose());
+    let verified = verifier.verify(y.expose(), &prover_response);
+    Transcript {
+        challenge,
+        prover_response,
+        verifier_value,
+        verified,
+    }
+}
+
+/// Validates the public protocol parameters before a run.
+///
+/// Checks that the modulus `p` is positive, that both projections of `g` (the real
+/// part and the sum `g1+g2`) are nonzero residues reduced modulo `p1` and `p1+p2`,
+/// and that both projections of `b` lie in the reduced ranges `[0, p1)` and
+/// `[0, p1+p2)`.
+///
+/// # Arguments
+/// * `g` - The public generator of the group.
+/// * `p` - The public neutrosophic modulus.
+/// * `b` - Peggy's public value `g^x mod p`.
+///
+/// # Errors
+/// * `NeutrosophicError::NonPositiveModulus` if `p` is not positive.
+/// * `NeutrosophicError::OutOfRange` if `g` or `b` is not reduced modulo `p`.
+pub fn validate_params(
+    g: &NeutrosophicNumber,
+    p: &NeutrosophicNumber,
+    b: &NeutrosophicNumber,
+) -> Result<(), NeutrosophicError> {
+    validate_group(g, p)?;
+    if !b.is_reduced(p) {
+        return Err(NeutrosophicError::OutOfRange(format!(
+            "public value b = {:.50} is not reduced modulo p",
+            b
+        )));
+    }
+    Ok(())
+}
+
+/// The `g` and `p` checks of `validate_params`.
+fn validate_group(g: &NeutrosophicNumber, p: &NeutrosophicNumber) -> Result<(), NeutrosophicError> {
+    if !p.is_positive() {
+        return Err(NeutrosophicError::NonPositiveModulus);
+    }
+    if !g.is_reduced(p) || g.a.is_zero() || g.indeterminate_total().is_zero() {
+        return Err(NeutrosophicError::OutOfRange(format!(
+            "generator g = {:.50} is not a nonzero residue modulo p",
+            g
+        )));
+    }
+    Ok(())
+}
+
+/// A validated generator and modulus, shared by every key and run in a group.
+///
+/// The fields are private so that a `GroupParams` always satisfies the checks of
+/// `new`. Primality of `p` is not checked; see `NeutrosophicNumber::is_probable_prime`
+/// and `neutrosophic_numbers::find_generator` for building a proper group.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GroupParams {
+    g: NeutrosophicNumber,
+    p: NeutrosophicNumber,
+}
+
+impl GroupParams {
+    /// Validates and bundles a generator and modulus.
+    ///
+    /// # Arguments
+    /// * `g` - The public generator of the group.
+    /// * `p` - The public neutrosophic modulus.
+    ///
+    /// # Errors
+    /// * `NeutrosophicError::NonPositiveModulus` if `p` is not positive.
+    /// * `NeutrosophicError::OutOfRange` if `g` is not a nonzero residue reduced
+    ///   modulo `p`, or if either projection of `g` is 1, which would make the group
+    ///   it generates trivial in that projection.
+    pub fn new(g: NeutrosophicNumber, p: NeutrosophicNumber) -> Result<Self, NeutrosophicError> {
+        validate_group(&g, &p)?;
+        if g.a.is_one() || g.indeterminate_total().is_one() {
+            return Err(NeutrosophicError::OutOfRange(format!(
+                "generator g = {:.50} has a trivial projection",
+                g
+            )));
+        }
+        Ok(GroupParams { g, p })
+    }
+
+    /// The public generator `g`.
+    pub fn g(&self) -> &NeutrosophicNumber {
+        &self.g
+    }
+
+    /// The public neutrosophic modulus `p`.
+    pub fn p(&self) -> &NeutrosophicNumber {
+        &self.p
+    }
+}
+
+/// Environment variable holding the generator `g` for `params_from_env`.
+pub const ENV_G: &str = "N1RZKP_G";
+
+/// Environment variable holding the modulus `p` for `params_from_env`.
+pub const ENV_P: &str = "N1RZKP_P";
+
+/// Reads and validates the group parameters from the environment.
+///
+/// `N1RZKP_G` and `N1RZKP_P` hold `g` and `p` in the `a + bI` notation accepted by
+/// `NeutrosophicNumber`'s `FromStr` implementation, e.g. `N1RZKP_P="7 + 4I"`.
+///
+/// # Errors
+/// * `NeutrosophicError::MissingEnvVar` if either variable is not set.
+/// * `NeutrosophicError::ParseError` if a value is not valid Unicode or not a
+///   neutrosophic number.
+/// * Any error from `GroupParams::new` if the parsed values are invalid.
+pub fn params_from_env() -> Result<GroupParams, NeutrosophicError> {
+    params_from_lookup(|name| std::env::var(name))
+}
+
+/// Reads and validates the group parameters through a variable lookup.
+///
+/// This is `params_from_env` with the environment replaced by `lookup`, which is
+/// called with `ENV_G` and `ENV_P` and answers like `std::env::var`. Tests pass an
+/// in-memory map instead of mutating the process environment.
+///
+/// # Arguments
+/// * `lookup` - Returns the value of the named variable.
+///
+/// # Errors
+/// The same as `params_from_env`.
+pub(crate) fn params_from_lookup(
+    lookup: impl Fn(&str) -> Result<String, std::env::VarError>,
+) -> Result<GroupParams, NeutrosophicError> {
+    let read = |name: &str| -> Result<NeutrosophicNumber, NeutrosophicError> {
+        match lookup(name) {
+            Ok(value) => value.parse(),
+            Err(std::env::VarError::NotPresent) => {
+                Err(NeutrosophicError::MissingEnvVar(name.to_string()))
+            }
+            Err(std::env::VarError::NotUnicode(_)) => Err(NeutrosophicError::ParseError(format!(
+                "{} is not valid Unicode",
+                name
+            ))),
+        }
+    };
+    GroupParams::new(read(ENV_G)?, read(ENV_P)?)
+}
+
+/// Simulates the Neutrosophic 1-Round ZKP protocol interaction.
+///
+/// This function executes the core logic of the ZKP, where Peggy (the prover)
+/// attempts to prove knowledge of the secret `x` to Victor (the verifier).
+///
+/// # Arguments
+/// * `g` - The public generator of the group.
+/// * `p` - The public neutrosophic modulus (prime).
+/// * `b` - Peggy's public key `g^x mod p`.
+/// * `x` - Peggy's secret key.
+/// * `rng` - The random number generator Victor draws `y` from. Passing a seeded
+///   generator makes the run reproducible.
+///
+/// # Returns
+/// `true` if the verification succeeds, `false` otherwise.
+pub fn neutrosophic_one_round_zkp_protocol<R: Rng + RandBigInt>(
+    g: &NeutrosophicNumber,
+    p: &NeutrosophicNumber,
+    b: &PublicKey,
+    x: &SecretKey,
+    rng: &mut R,
+) -> bool {
+    let prover = Prover {
+        x: Secret::new(x.expose().clone()),
+    };
+    let verifier = Verifier::new(g.clone(), p.clone(), b.clone());
+
+    run_rounds(&prover, &verifier, 1, rng)
+}
+
+/// Runs the challenge/response exchange between Peggy and Victor several times.
+///
+/// A single round can be passed by a cheating prover with some probability that
+/// depends on the (unproven) group structure. Repeating the exchange with a fresh
+/// `y` each round reduces that soundness error exponentially in `rounds`.
+///
+/// # Arguments
+/// * `prover` - Peggy, holding the secret `x`.
+/// * `verifier` - Victor, holding the public parameters.
+/// * `rounds` - The number of independent rounds to run.
+/// * `rng` - The random number generator Victor draws each `y` from.
+///
+/// # Returns
+/// `true` only if every round verifies. Zero rounds give `false`, since Peggy has
+/// not answered any challenge.
+pub fn run_rounds<R: Rng + RandBigInt>(
+    prover: &Prover,
+    verifier: &Verifier,
+    rounds: usize,
+    rng: &mut R,
+) -> bool {
+    rounds >= 1
+        && (0..rounds).all(|round| {
+            debug!("starting round {} of {}", round + 1, rounds);
+
+            // Steps 1-2 (Victor): Generate a random secret `y` and send `c = g^y mod p` to Peggy.
+            let (c, y) = verifier.challenge(rng);
+
+            // Step 3 (Peggy): Compute the response `r = c^x mod p` using her secret `x`.
+            let r_peggy = prover.respond(&c, &verifier.p);
+
+            // Step 4 (Victor): Check the response against `r' = b^y mod p` using his secret `y`.
+            verifier.verify(y.expose(), &r_peggy)
+        })
+}
+
+/// Estimates the probability that a cheating prover passes `rounds` rounds.
+///
+/// A prover who does not know `x` passes a single round by guessing the response,
+/// which succeeds with probability about `1/order` for a group of order `order`, so
+/// `rounds` independent rounds give `(1/order)^rounds`. This is a heuristic for
+/// choosing `rounds`, not a proof: the protocol itself is not secure.
+///
+/// Orders beyond the range of `f64` count as infinite and give `0.0`, as does a
+/// result that underflows. `rounds` above `i32::MAX` is clamped, which can only make
+/// the already negligible result smaller.
+///
+/// # Arguments
+/// * `order` - The order of the group the challenges live in.
+/// * `rounds` - The number of rounds run.
+///
+/// # Panics
+/// Panics if `order` is not positive.
+pub fn soundness_error(order: &BigInt, rounds: usize) -> f64 {
+    assert!(order.is_positive(), "group order must be positive");
+    let order = order.to_f64().unwrap_or(f64::INFINITY);
+    let rounds = i32::try_from(rounds).unwrap_or(i32::MAX);
+    order.recip().powi(rounds)
+}
+
+/// Domain separation tag for the nonce blinding of `Verifier::challenge_with_nonce`.
+const CHALLENGE_NONCE_DOMAIN: &[u8] = b"n1rzkp/challenge-nonce/v1";
+
+/// Derives the additive blinding term `H(nonce)` of `Verifier::challenge_with_nonce`.
+fn nonce_exponent(nonce: &[u8]) -> NeutrosophicNumber {
+    let component = |index: u8| -> BigInt {
+        let mut hasher = Sha256::new();
+        hasher.update(CHALLENGE_NONCE_DOMAIN);
+        hasher.update([index]);
+        hasher.update(nonce);
+        BigInt::from_bytes_be(Sign::Plus, &hasher.finalize())
+    };
+    NeutrosophicNumber::new(component(0), component(1))
+}
+
+/// Domain separation tag for the seeded challenge derivation.
+const CHALLENGE_SEED_DOMAIN: &[u8] = b"n1rzkp/challenge-seed/v1";
+
+/// Derives a reproducible challenge `c = g^y mod p` from a seed, for testing.
+///
+/// Each component of `y` is `CHALLENGE_BIT_SIZE` bits of SHA-256 output in counter
+/// mode: the concatenated digests of the domain tag, the component index, a 32-bit
+/// big-endian block counter, and `seed`, read as an unsigned big-endian integer.
+///
+/// Unlike the Fiat-Shamir derivation, `y` depends only on `seed` and not on the
+/// statement, so it pins down a run without binding the challenge to `g`, `p`, `b`.
+///
+/// # Arguments
+/// * `g` - The public generator of the group.
+/// * `p` - The public neutrosophic modulus (prime).
+/// * `seed` - The bytes `y` is derived from.
+///
+/// # Returns
+/// The pair `(c, y)`, as returned by `Verifier::challenge`.
+pub fn challenge_from_seed(
+    g: &NeutrosophicNumber,
+    p: &NeutrosophicNumber,
+    seed: &[u8],
+) -> (NeutrosophicNumber, NeutrosophicNumber) {
+    let component = |index: u8| -> BigInt {
+        let blocks = CHALLENGE_BIT_SIZE.div_ceil(256) as u32;
+        let mut bytes = Vec::with_capacity(blocks as usize * 32);
+        for counter in 0..blocks {
+            let mut hasher = Sha256::new();
+            hasher.update(CHALLENGE_SEED_DOMAIN);
+            hasher.update([index]);
+            hasher.update(counter.to_be_bytes());
+            hasher.update(seed);
+            bytes.extend_from_slice(&hasher.finalize());
+        }
+        BigInt::from_bytes_be(Sign::Plus, &bytes)
+    };
+    let y = NeutrosophicNumber::new(component(0), component(1));
+    let c = g.pow_mod(&y, p);
+    (c, y)
+}
+
+/// Domain separation tag for the Fiat-Shamir challenge derivation.
+const FIAT_SHAMIR_DOMAIN: &[u8] = b"n1rzkp/fiat-shamir/v1";
+
+/// A non-interactive proof produced by `prove_noninteractive`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct NeutrosophicProof {
+    /// Peggy's response `r = c^x mod p` to the derived challenge.
+    pub r: NeutrosophicNumber,
+}
+
+/// Derives Victor's secret `y` from the public transcript `g || p || b`.
+///
+/// Each component of `y` is the SHA-256 digest of the domain tag, a component
+/// index, and the byte encodings (`to_bytes_be`) of `g`, `p`, and `b`, read as an
+/// unsigned big-endian integer.
+fn fiat_shamir_exponent(
+    g: &NeutrosophicNumber,
+    p: &NeutrosophicNumber,
+    b: &NeutrosophicNumber,
+) -> NeutrosophicNumber {
+    let component = |index: u8| -> BigInt {
+        let mut hasher = Sha256::new();
+        hasher.update(FIAT_SHAMIR_DOMAIN);
+        hasher.update([index]);
+        for value in [g, p, b] {
+            hasher.update(value.to_bytes_be());
+        }
+        BigInt::from_bytes_be(Sign::Plus, &hasher.finalize())
+    };
+    NeutrosophicNumber::new(component(0), component(1))
+}
+
+/// Produces a non-interactive proof via the Fiat-Shamir transform.
+///
+/// Victor's random `y` is replaced by a hash of the public transcript
+/// (see `fiat_shamir_exponent`), and Peggy answers the challenge `c = g^y mod p`
+/// with `r = c^x mod p`.
+///
+/// WARNING: Because `y` is derived from public data, anyone can compute
+/// `b^y mod p` and forge `r` without knowing `x`. This transform illustrates the
+/// construction only; like the interactive protocol, it is NOT SECURE.
+///
+/// # Arguments
+/// * `g` - The public generator of the group.
+/// * `p` - The public neutrosophic modulus (prime).
+/// * `b` - Peggy's public key `g^x mod p`.
+/// * `x` - Peggy's secret key.
+pub fn prove_noninteractive(
+    g: &NeutrosophicNumber,
+    p: &NeutrosophicNumber,
+    b: &PublicKey,
+    x: &SecretKey,
+) -> NeutrosophicProof {
+    let y = Secret::new(fiat_shamir_exponent(g, p, &b.0));
+    let c = g.pow_mod(y.expose(), p);
+    let prover = Prover {
+        x: Secret::new(x.expose().clone()),
+    };
+    NeutrosophicProof {
+        r: prover.respond(&c, p),
+    }
+}
+
+/// Verifies a proof produced by `prove_noninteractive`.
+///
+/// # Arguments
+/// * `g` - The public generator of the group.
+/// * `p` - The public neutrosophic modulus (prime).
+/// * `b` - Peggy's public key `g^x mod p`.
+/// * `proof` - The proof to check.
+///
+/// # Returns
+/// `true` if the response matches `b^y mod p` for the transcript-derived `y`.
+pub fn verify_noninteractive(
+    g: &NeutrosophicNumber,
+    p: &NeutrosophicNumber,
+    b: &PublicKey,
+    proof: &NeutrosophicProof,
+) -> bool {
+    let y = Secret::new(fiat_shamir_exponent(g, p, &b.0));
+    let verifier = Verifier::new(g.clone(), p.clone(), b.clone());
+    verifier.verify(y.expose(), &proof.r)
+}
+
+/// Domain separation tag for the shared challenge of `prove_conjunction`.
+const CONJUNCTION_DOMAIN: &[u8] = b"n1rzkp/conjunction/v1";
+
+/// A proof of knowledge of every secret behind a list of public keys, produced by
+/// `prove_conjunction`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct AggregateProof {
+    /// Random bytes mixed into the shared challenge, so repeated proofs differ.
+    pub salt: [u8; 32],
+    /// Peggy's responses `r_i = c^x_i mod p`, in the order of the public keys.
+    pub responses: Vec<NeutrosophicNumber>,
+}
+
+/// Derives the shared `y` of a conjunction from `g`, `p`, the salt, and every `b_i`.
+///
+/// Each component of `y` is the SHA-256 digest of the domain tag, a component index,
+/// the salt, the number of statements as a 64-bit big-endian integer, and the byte
+/// encodings (`to_bytes_be`) of `g`, `p`, and each `b_i`, read as an unsigned
+/// big-endian integer.
+fn conjunction_exponent(
+    g: &NeutrosophicNumber,
+    p: &NeutrosophicNumber,
+    bs: &[PublicKey],
+    salt: &[u8; 32],
+) -> NeutrosophicNumber {
+    let component = |index: u8| -> BigInt {
+        let mut hasher = Sha256::new();
+        hasher.update(CONJUNCTION_DOMAIN);
+        hasher.update([index]);
+        hasher.update(salt);
+        hasher.update((bs.len() as u64).to_be_bytes());
+        hasher.update(g.to_bytes_be());
+        hasher.update(p.to_bytes_be());
+        for b in bs {
+            hasher.update(b.0.to_bytes_be());
+        }
+        BigInt::from_bytes_be(Sign::Plus, &hasher.finalize())
+    };
+    NeutrosophicNumber::new(component(0), component(1))
+}
+
+/// Proves knowledge of several secrets `x_1, ..., x_n` at once.
+///
+/// A single `y` is derived by hashing a fresh salt with all the public keys (see
+/// `conjunction_exponent`), and Peggy answers the shared challenge `c = g^y mod p`
+/// with `r_i = c^x_i mod p` for every statement.
+///
+/// WARNING: As with `prove_noninteractive`, anyone can compute `b_i^y mod p` from
+/// public data, so this illustrates the construction only and is NOT SECURE.
+///
+/// # Arguments
+/// * `secrets` - Peggy's secret keys `x_i`.
+/// * `g` - The public generator of the group.
+/// * `p` - The public neutrosophic modulus (prime).
+/// * `bs` - The public keys `b_i = g^x_i mod p`, in the same order as `secrets`.
+/// * `rng` - A mutable reference to a random number generator, used for the salt.
+///
+/// # Panics
+/// Panics if `secrets` and `bs` have different lengths.
+pub fn prove_conjunction<R: Rng + RandBigInt>(
+    secrets: &[SecretKey],
+    g: &NeutrosophicNumber,
+    p: &NeutrosophicNumber,
+    bs: &[PublicKey],
+    rng: &mut R,
+) -> AggregateProof {
+    assert_eq!(
+        secrets.len(),
+        bs.len(),
+        "each secret key needs exactly one public key"
+    );
+    let mut salt = [0u8; 32];
+    rng.fill(&mut salt);
+    let y = Secret::new(conjunction_exponent(g, p, bs, &salt));
+    let c = g.pow_mod(y.expose(), p);
+    let responses = secrets
+        .iter()
+        .map(|key| c.pow_mod(key.expose(), p))
+        .collect();
+    AggregateProof { salt, responses }
+}
+
+/// Verifies a proof produced by `prove_conjunction`.
+///
+/// # Arguments
+/// * `g` - The public generator of the group.
+/// * `p` - The public neutrosophic modulus (prime).
+/// * `bs` - The public keys `b_i`, in the order they were proven.
+/// * `proof` - The proof to check.
+///
+/// # Returns
+/// `true` if the proof has one response per public key and every response matches
+/// `b_i^y mod p` for the shared `y`. A single wrong response fails the whole proof.
+pub fn verify_conjunction(
+    g: &NeutrosophicNumber,
+    p: &NeutrosophicNumber,
+    bs: &[PublicKey],
+    proof: &AggregateProof,
+) -> bool {
+    if proof.responses.len() != bs.len() {
+        return false;
+    }
+    let y = Secret::new(conjunction_exponent(g, p, bs, &proof.salt));
+    bs.iter().zip(&proof.responses).all(|(b, r)| {
+        let verifier = Verifier {
+            g: g.clone(),
+            p: p.clone(),
+            b: b.0.clone(),
+        };
+        verifier.verify(y.expose(), r)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neutrosophic_numbers::{generate_neutrosophic_prime, neu};
+    use num_bigint::ToBigInt;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    /// Small parameters shared by the prover/verifier tests:
+    /// p = 7+4I (7 and 11 are prime), g = 3+2I, x = 4+1I.
+    fn small_params() -> (NeutrosophicNumber, NeutrosophicNumber, NeutrosophicNumber) {
+        let g = NeutrosophicNumber::new(3.to_bigint().unwrap(), 2.to_bigint().unwrap());
+        let p = NeutrosophicNumber::new(7.to_bigint().unwrap(), 4.to_bigint().unwrap());
+        let x = NeutrosophicNumber::new(4.to_bigint().unwrap(), 1.to_bigint().unwrap());
+        (g, p, x)
+    }
+
+    #[test]
+    fn test_prover_respond() {
+        let (_, p, x) = small_params();
+        let prover = Prover { x: Secret::new(x) };
+        // c = 2+1I: real part 2^4 mod 7 = 2, sum 3^5 mod 11 = 1, so r = 2 + (1-2)I
+        let c = NeutrosophicNumber::new(2.to_bigint().unwrap(), 1.to_bigint().unwrap());
+        let expected = NeutrosophicNumber::new(2.to_bigint().unwrap(), (-1).to_bigint().unwrap());
+        assert_eq!(prover.respond(&c, &p), expected);
+    }
+
+    #[test]
+    fn test_verifier_verify() {
+        let (g, p, x) = small_params();
+        let b = g.pow_mod(&x, &p);
+        let verifier = Verifier { g, p, b };
+        let y = NeutrosophicNumber::new(3.to_bigint().unwrap(), 1.to_bigint().unwrap());
+        // b = 4-3I, so b^y: real part 4^3 mod 7 = 1, sum 1^4 mod 11 = 1, giving 1+0I.
+        let r = NeutrosophicNumber::new(1.to_bigint().unwrap(), 0.to_bigint().unwrap());
+        assert!(verifier.verify(&y, &r));
+        let wrong_r = NeutrosophicNumber::new(2.to_bigint().unwrap(), 0.to_bigint().unwrap());
+        assert!(!verifier.verify(&y, &wrong_r));
+    }
+
+    #[test]
+    fn test_verifier_challenge() {
+        let (g, p, x) = small_params();
+        let b = g.pow_mod(&x, &p);
+        let verifier = Verifier {
+            g: g.clone(),
+            p: p.clone(),
+            b,
+        };
+        let mut rng = StdRng::seed_from_u64(1);
+        let (c, y) = verifier.challenge(&mut rng);
+        assert_eq!(c, g.pow_mod(y.expose(), &p));
+    }
+
+    #[test]
+    fn test_protocol_is_deterministic_with_seeded_rng() {
+        let (g, p, x) = small_params();
+        let b = g.pow_mod(&x, &p);
+        let verifier = Verifier {
+            g: g.clone(),
+            p: p.clone(),
+            b: b.clone(),
+        };
+
+        // Two generators with the same seed must produce the same challenge.
+        let (c1, y1) = verifier.challenge(&mut StdRng::seed_from_u64(2024));
+        let (c2, y2) = verifier.challenge(&mut StdRng::seed_from_u64(2024));
+        assert_eq!(y1.expose(), y2.expose());
+        assert_eq!(c1, c2);
+
+        // And the full protocol run is reproducible as well.
+        let (b, x) = (PublicKey(b), SecretKey::new(x));
+        let mut rng1 = StdRng::seed_from_u64(2024);
+        let mut rng2 = StdRng::seed_from_u64(2024);
+        assert_eq!(
+            neutrosophic_one_round_zkp_protocol(&g, &p, &b, &x, &mut rng1),
+            neutrosophic_one_round_zkp_protocol(&g, &p, &b, &x, &mut rng2)
+        );
+    }
+
+    #[test]
+    fn test_run_rounds_honest_prover() {
+        let (g, p, x) = small_params();
+        let b = g.pow_mod(&x, &p);
+        let prover = Prover { x: Secret::new(x) };
+        let verifier = Verifier { g, p, b };
+        let mut rng = StdRng::seed_from_u64(17);
+        for rounds in [1, 5, 20] {
+            assert!(run_rounds(&prover, &verifier, rounds, &mut rng));
+        }
+    }
+
+    #[test]
+    fn test_run_rounds_rejects_zero_rounds() {
+        let (g, p, x) = small_params();
+        let b = g.pow_mod(&x, &p);
+        let prover = Prover { x: Secret::new(x) };
+        let verifier = Verifier { g, p, b };
+        let mut rng = StdRng::seed_from_u64(131);
+        assert!(!run_rounds(&prover, &verifier, 0, &mut rng));
+    }
+
+    #[test]
+    fn test_run_rounds_dishonest_prover() {
+        let (g, p, x) = small_params();
+        let b = g.pow_mod(&x, &p);
+        // A wrong secret whose public value differs from b.
+        let x_fake = NeutrosophicNumber::from((5, 1));
+        assert_ne!(g.pow_mod(&x_fake, &p), b);
+        let prover = Prover {
+            x: Secret::new(x_fake),
+        };
+        let verifier = Verifier { g, p, b };
+
+        // In this tiny group a single round is passed by luck fairly often, but the
+        // chance of passing every round vanishes as the number of rounds grows.
+        let mut rng = StdRng::seed_from_u64(19);
+        let passes = (0..50)
+            .filter(|_| run_rounds(&prover, &verifier, 1, &mut rng))
+            .count();
+        assert!(passes < 50);
+        assert!(!run_rounds(&prover, &verifier, 40, &mut rng));
+    }
+
+    #[test]
+    fn test_noninteractive_proof_verifies() {
+        let mut rng = StdRng::seed_from_u64(23);
+        let p = generate_neutrosophic_prime(&mut rng, 64, 12);
+        let g = NeutrosophicNumber::from((3, 2));
+        let x = SecretKey::generate(&mut rng, 64);
+        let b = x.public_key(&g, &p);
+
+        let proof = prove_noninteractive(&g, &p, &b, &x);
+        assert!(verify_noninteractive(&g, &p, &b, &proof));
+        // The challenge is deterministic, so proving again yields the same proof.
+        assert_eq!(prove_noninteractive(&g, &p, &b, &x), proof);
+    }
+
+    #[test]
+    fn test_noninteractive_proof_rejects_tampered_b() {
+        let mut rng = StdRng::seed_from_u64(29);
+        let p = generate_neutrosophic_prime(&mut rng, 64, 12);
+        let g = NeutrosophicNumber::from((3, 2));
+        let x = SecretKey::generate(&mut rng, 64);
+        let b = x.public_key(&g, &p);
+        let proof = prove_noninteractive(&g, &p, &b, &x);
+
+        let tampered_b = PublicKey(b.0 + NeutrosophicNumber::from((1, 0)));
+        assert!(!verify_noninteractive(&g, &p, &tampered_b, &proof));
+    }
+
+    #[test]
+    fn test_validate_params_accepts_valid() {
+        let (g, p, x) = small_params();
+        let b = g.pow_mod(&x, &p);
+        assert_eq!(validate_params(&g, &p, &b), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_params_rejects_out_of_range() {
+        let (g, p, x) = small_params();
+        let b = g.pow_mod(&x, &p);
+
+        // Non-positive modulus: 7 + (-8)I has a negative sum.
+        let bad_p = NeutrosophicNumber::from((7, -8));
+        assert_eq!(
+            validate_params(&g, &bad_p, &b),
+            Err(NeutrosophicError::NonPositiveModulus)
+        );
+
+        // g's real part is not reduced mod 7, its sum is zero, or its sum is not reduced mod 11.
+        for bad_g in [(9, 0), (3, -3), (3, 9)] {
+            assert!(matches!(
+                validate_params(&NeutrosophicNumber::from(bad_g), &p, &b),
+                Err(NeutrosophicError::OutOfRange(_))
+            ));
+        }
+
+        // b shifted by p is congruent, but no longer reduced.
+        let bad_b = b + p.clone();
+        assert!(matches!(
+            validate_params(&g, &p, &bad_b),
+            Err(NeutrosophicError::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_run_with_transcript_honest_prover() {
+        let (g, p, x) = small_params();
+        let b = g.pow_mod(&x, &p);
+        let prover = Prover {
+            x: Secret::new(x.clone()),
+        };
+        let verifier = Verifier {
+            g: g.clone(),
+            p: p.clone(),
+            b,
+        };
+        let mut rng = StdRng::seed_from_u64(53);
+        let transcript = run_with_transcript(&prover, &verifier, &mut rng);
+
+        // Replaying the same seed reproduces the challenge.
+        let (c, _) = verifier.challenge(&mut StdRng::seed_from_u64(53));
+        assert_eq!(transcript.challenge, c);
+        assert_eq!(transcript.prover_response, c.pow_mod(&x, &p));
+        assert!(transcript.verified);
+        assert_eq!(transcript.prover_response, transcript.verifier_value);
+    }
+
+    #[test]
+    fn test_run_with_transcript_verified_iff_values_match() {
+        let (g, p, x) = small_params();
+        let b = g.pow_mod(&x, &p);
+        let verifier = Verifier { g, p, b };
+        let mut rng = StdRng::seed_from_u64(59);
+        for secret in [x, NeutrosophicNumber::from((5, 1))] {
+            let prover = Prover {
+                x: Secret::new(secret),
+            };
+            for _ in 0..10 {
+                let (_, y) = verifier.challenge(&mut rng.clone());
+                let transcript = run_with_transcript(&prover, &verifier, &mut rng);
+                assert_eq!(
+                    transcript.verified,
+                    transcript.prover_response == transcript.verifier_value
+                );
+                assert_eq!(
+                    transcript.verified,
+                    verifier.verify(y.expose(), &transcript.prover_response)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_run_rounds_with_typed_keys() {
+        let (g, p, x) = small_params();
+        let key = SecretKey::new(x);
+        let public_key = key.public_key(&g, &p);
+        let verifier = Verifier::new(g, p, public_key);
+        let prover = Prover::new(key);
+        assert!(run_rounds(
+            &prover,
+            &verifier,
+            5,
+            &mut StdRng::seed_from_u64(89)
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_protocol_params_serde_round_trip() {
+        let mut rng = StdRng::seed_from_u64(97);
+        let p = generate_neutrosophic_prime(&mut rng, 64, 12);
+        let g = generate_random_neutrosophic(&mut rng, 64);
+        let x = generate_random_neutrosophic(&mut rng, 64);
+        let params = ProtocolParams::new(g.clone(), p.clone(), g.pow_mod(&x, &p));
+
+        let json = serde_json::to_string(&params).unwrap();
+        assert!(json.starts_with(r#"{"version":"1","g":"#));
+        let decoded: ProtocolParams = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, params);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_protocol_params_serde_preserves_version() {
+        let (g, p, x) = small_params();
+        let b = g.pow_mod(&x, &p);
+        let params = ProtocolParams {
+            version: "2-draft".to_string(),
+            ..ProtocolParams::new(g, p, b)
+        };
+        let json = serde_json::to_string(&params).unwrap();
+        let decoded: ProtocolParams = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.version, "2-draft");
+        assert_eq!(decoded, params);
+    }
+
+    #[test]
+    fn test_verify_detailed_reports_each_failure() {
+        let (g, p, x) = small_params();
+        let b = g.pow_mod(&x, &p);
+        let verifier = Verifier {
+            g: g.clone(),
+            p: p.clone(),
+            b: b.clone(),
+        };
+        let y = NeutrosophicNumber::from((3, 1));
+        let r = verifier.verification_value(&y);
+        assert_eq!(verifier.verify_detailed(&y, &r), Ok(()));
+
+        // Off by one in the real part, but still reduced.
+        let wrong_r = NeutrosophicNumber::from((2, 0));
+        assert_eq!(
+            verifier.verify_detailed(&y, &wrong_r),
+            Err(VerifyFailure::Mismatch)
+        );
+
+        // Congruent to the right answer, but not reduced.
+        let shifted_r = r.clone() + p.clone();
+        assert_eq!(
+            verifier.verify_detailed(&y, &shifted_r),
+            Err(VerifyFailure::ResponseOutOfRange)
+        );
+
+        let bad_verifier = Verifier {
+            g,
+            p: NeutrosophicNumber::from((7, -8)),
+            b,
+        };
+        assert_eq!(
+            bad_verifier.verify_detailed(&y, &r),
+            Err(VerifyFailure::InvalidParams(
+                NeutrosophicError::NonPositiveModulus
+            ))
+        );
+    }
+
+    #[test]
+    fn test_challenge_from_seed_is_deterministic() {
+        let (g, p, x) = small_params();
+        let (c1, y1) = challenge_from_seed(&g, &p, b"seed");
+        let (c2, y2) = challenge_from_seed(&g, &p, b"seed");
+        assert_eq!(c1, c2);
+        assert_eq!(y1, y2);
+        assert_eq!(c1, g.pow_mod(&y1, &p));
+        assert!(y1.a.bits() <= CHALLENGE_BIT_SIZE as u64);
+
+        let (_, y3) = challenge_from_seed(&g, &p, b"other seed");
+        assert_ne!(y1, y3);
+
+        // The seeded challenge plugs into the usual round.
+        let b = g.pow_mod(&x, &p);
+        let verifier = Verifier::new(g, p, PublicKey(b));
+        let prover = Prover::new(SecretKey::new(x));
+        assert!(verifier.verify(&y1, &prover.respond(&c1, &verifier.p)));
+    }
+
+    #[test]
+    fn test_verify_rejects_oversized_response() {
+        use std::time::{Duration, Instant};
+
+        let (g, p, x) = small_params();
+        let b = g.pow_mod(&x, &p);
+        let verifier = Verifier::new(g, p, PublicKey(b));
+        let (c, y) = challenge_from_seed(&verifier.g, &verifier.p, b"oversized");
+        let honest = Prover::new(SecretKey::new(x)).respond(&c, &verifier.p);
+        assert_eq!(verifier.verify_detailed(&y, &honest), Ok(()));
+
+        // A response of a million bits is turned away without being reduced or compared.
+        let huge = NeutrosophicNumber::new(BigInt::from(1) << 1_000_000u32, BigInt::from(1));
+        let start = Instant::now();
+        assert_eq!(
+            verifier.verify_detailed(&y, &huge),
+            Err(VerifyFailure::ResponseOutOfRange)
+        );
+        assert!(!verifier.verify(&y, &huge));
+        assert!(start.elapsed() < Duration::from_secs(1));
+
+        // The bound leaves room for the extra bit of p1+p2: with p = 7+4I, the
+        // reduced response 0 + 10I has 4 bits while p has 3.
+        assert!(!verifier.response_too_large(&neu(0, 10)));
+        assert!(verifier.response_too_large(&neu(0, 16)));
+    }
+
+    #[test]
+    fn test_verify_batch_mixed_items() {
+        let (g, p, _) = small_params();
+        let verifier = Verifier::new(g.clone(), p.clone(), PublicKey(neu(1, 0)));
+        let mut rng = StdRng::seed_from_u64(123);
+
+        let mut items = Vec::new();
+        let mut expected = Vec::new();
+        for i in 0..8 {
+            let key = SecretKey::generate(&mut rng, 16);
+            let b = key.public_key(&g, &p).0;
+            let (c, y) = verifier.challenge(&mut rng);
+            let mut r = Prover::new(key).respond(&c, &p);
+            // Every third response is tampered with.
+            let valid = i % 3 != 0;
+            if !valid {
+                r = r.mul_mod(&g, &p);
+            }
+            items.push((b, r, y.expose().clone()));
+            expected.push(valid);
+        }
+        assert_eq!(verifier.verify_batch(&items), expected);
+        assert!(verifier.verify_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_group_params_validation() {
+        let (g, p, x) = small_params();
+        let group = GroupParams::new(g.clone(), p.clone()).unwrap();
+        assert_eq!((group.g(), group.p()), (&g, &p));
+
+        let key = SecretKey::new(x);
+        let public_key = key.public_key_for(&group);
+        assert_eq!(public_key.0, g.pow_mod(key.expose(), &p));
+        let verifier = Verifier::from_group(group, public_key);
+        let prover = Prover::new(key);
+        assert!(run_rounds(
+            &prover,
+            &verifier,
+            3,
+            &mut StdRng::seed_from_u64(124)
+        ));
+
+        for bad_p in [neu(0, 7), neu(7, -7), neu(-7, 20)] {
+            assert_eq!(
+                GroupParams::new(g.clone(), bad_p),
+                Err(NeutrosophicError::NonPositiveModulus)
+            );
+        }
+        // Unreduced, zero, or trivial generators are out of range.
+        for bad_g in [neu(8, 0), neu(0, 2), neu(3, -3), neu(1, 2), neu(3, -2)] {
+            assert!(matches!(
+                GroupParams::new(bad_g, p.clone()),
+                Err(NeutrosophicError::OutOfRange(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_challenge_with_nonce_binds_the_nonce() {
+        // In a group as small as 7+4I, a wrong nonce still passes with probability
+        // about 1/30, so use 64-bit primes.
+        let mut rng = StdRng::seed_from_u64(125);
+        let p = generate_neutrosophic_prime(&mut rng, 64, 12);
+        let g = neu(3, 2);
+        let key = SecretKey::generate(&mut rng, 64);
+        let verifier = Verifier::new(g.clone(), p.clone(), key.public_key(&g, &p));
+        let prover = Prover::new(key);
+
+        let (c, y0) = verifier.challenge_with_nonce(b"session-1", &mut rng);
+        let r = prover.respond(&c, &p);
+        assert!(verifier.verify_with_nonce(b"session-1", y0.expose(), &r));
+        assert!(!verifier.verify_with_nonce(b"session-2", y0.expose(), &r));
+        // Without the nonce, y0 alone does not explain the challenge either.
+        assert!(!verifier.verify(y0.expose(), &r));
+    }
+
+    #[test]
+    fn test_soundness_error_estimates() {
+        let close = |actual: f64, expected: f64| (actual - expected).abs() <= 1e-12 * expected;
+        assert!(close(soundness_error(&BigInt::from(30), 1), 1.0 / 30.0));
+        assert!(close(soundness_error(&BigInt::from(30), 3), 1.0 / 27_000.0));
+        assert_eq!(soundness_error(&BigInt::from(30), 0), 1.0);
+        assert_eq!(soundness_error(&BigInt::from(1), 5), 1.0);
+
+        // Huge orders and round counts clamp to zero instead of overflowing.
+        assert_eq!(soundness_error(&(BigInt::from(1) << 2048u32), 1), 0.0);
+        assert_eq!(soundness_error(&BigInt::from(1u64 << 40), 100), 0.0);
+        assert_eq!(soundness_error(&BigInt::from(2), usize::MAX), 0.0);
+    }
+
+    #[test]
+    fn test_params_from_lookup() {
+        use std::collections::HashMap;
+        use std::env::VarError;
+
+        let lookup_in = |vars: &[(&str, &str)]| {
+            let map: HashMap<String, String> = vars
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect();
+            move |name: &str| map.get(name).cloned().ok_or(VarError::NotPresent)
+        };
+
+        let group = params_from_lookup(lookup_in(&[(ENV_G, "3 + 2I"), (ENV_P, "7 + 4I")])).unwrap();
+        assert_eq!((group.g(), group.p()), (&neu(3, 2), &neu(7, 4)));
+
+        assert_eq!(
+            params_from_lookup(lookup_in(&[(ENV_G, "3 + 2I")])),
+            Err(NeutrosophicError::MissingEnvVar(ENV_P.to_string()))
+        );
+        assert_eq!(
+            params_from_lookup(lookup_in(&[])),
+            Err(NeutrosophicError::MissingEnvVar(ENV_G.to_string()))
+        );
+
+        assert!(matches!(
+            params_from_lookup(lookup_in(&[(ENV_G, "3 + 2I"), (ENV_P, "seven")])),
+            Err(NeutrosophicError::ParseError(_))
+        ));
+        assert!(matches!(
+            params_from_lookup(|_| Err(VarError::NotUnicode("\u{fffd}".into()))),
+            Err(NeutrosophicError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_conjunction_verifies_and_fails_on_one_bad_secret() {
+        let mut rng = StdRng::seed_from_u64(127);
+        let p = generate_neutrosophic_prime(&mut rng, 64, 12);
+        let g = NeutrosophicNumber::from((3, 2));
+        let secrets: Vec<SecretKey> = (0..4).map(|_| SecretKey::generate(&mut rng, 64)).collect();
+        let bs: Vec<PublicKey> = secrets.iter().map(|x| x.public_key(&g, &p)).collect();
+
+        let proof = prove_conjunction(&secrets, &g, &p, &bs, &mut rng);
+        assert_eq!(proof.responses.len(), 4);
+        assert!(verify_conjunction(&g, &p, &bs, &proof));
+        // Dropping a statement changes the shared challenge and the count.
+        assert!(!verify_conjunction(&g, &p, &bs[..3], &proof));
+
+        let mut corrupted: Vec<SecretKey> = secrets
+            .iter()
+            .map(|x| SecretKey::new(x.expose().clone()))
+            .collect();
+        corrupted[2] = SecretKey::new(secrets[2].expose() + &NeutrosophicNumber::from((1, 0)));
+        let proof = prove_conjunction(&corrupted, &g, &p, &bs, &mut rng);
+        assert!(!verify_conjunction(&g, &p, &bs, &proof));
+    }
+}
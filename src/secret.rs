@@ -0,0 +1,75 @@
+use crate::neutrosophic_numbers::NeutrosophicNumber;
+use core::fmt;
+
+/// Wraps a `NeutrosophicNumber` that holds secret material, such as Peggy's key `x`
+/// or Victor's per-session `y`.
+///
+/// The wrapped value is wiped in place (see `NeutrosophicNumber::wipe`) when the
+/// `Secret` is dropped, and its `Debug` output is redacted. With the `zeroize`
+/// feature enabled, the wrapper also implements `Zeroize` and `ZeroizeOnDrop`.
+pub struct Secret(NeutrosophicNumber);
+
+impl Secret {
+    /// Takes ownership of a secret value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The secret neutrosophic number.
+    pub fn new(value: NeutrosophicNumber) -> Self {
+        Secret(value)
+    }
+
+    /// Borrows the secret value for use in a computation.
+    pub fn expose(&self) -> &NeutrosophicNumber {
+        &self.0
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.0.wipe();
+    }
+}
+
+/// Redacts the secret value so it cannot leak through logs.
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Secret(..)")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Secret {
+    fn zeroize(&mut self) {
+        self.0.wipe();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Secret {}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_redacts_and_drops() {
+        let secret = Secret::new(NeutrosophicNumber::from((3, -1)));
+        assert_eq!(secret.expose(), &NeutrosophicNumber::from((3, -1)));
+        assert_eq!(format!("{:?}", secret), "Secret(..)");
+        drop(secret);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_secret_implements_zeroize() {
+        use zeroize::{Zeroize, ZeroizeOnDrop};
+
+        fn assert_zeroize_on_drop<T: Zeroize + ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<Secret>();
+
+        let mut n = NeutrosophicNumber::from((3, -1));
+        n.zeroize();
+        assert_eq!(n, NeutrosophicNumber::from((0, 0)));
+    }
+}
@@ -0,0 +1,99 @@
+//! `wasm-bindgen` wrappers for running the demo protocol in a browser.
+//!
+//! The wrappers take an explicit seed instead of relying on `rand::thread_rng`, so a
+//! run is reproducible and needs no entropy source. Available with the `wasm` feature.
+
+use crate::keys::SecretKey;
+use crate::neutrosophic_numbers::{NeutrosophicNumber, generate_random_neutrosophic};
+use crate::protocol::neutrosophic_one_round_zkp_protocol;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use wasm_bindgen::prelude::*;
+
+/// The outcome of one demo run, with the public parameters truncated for display.
+#[wasm_bindgen]
+pub struct JsProtocolRun {
+    g: NeutrosophicNumber,
+    p: NeutrosophicNumber,
+    b: NeutrosophicNumber,
+    verified: bool,
+}
+
+#[wasm_bindgen]
+impl JsProtocolRun {
+    /// Generates `bits`-bit parameters from `seed` and runs one honest protocol round.
+    #[wasm_bindgen(constructor)]
+    pub fn new(bits: u32, seed: u64) -> JsProtocolRun {
+        let bits = bits as usize;
+        let mut rng = StdRng::seed_from_u64(seed);
+        let p = generate_random_neutrosophic(&mut rng, bits);
+        let g = generate_random_neutrosophic(&mut rng, bits);
+        let x = SecretKey::generate(&mut rng, bits);
+        let b = x.public_key(&g, &p);
+        let verified = neutrosophic_one_round_zkp_protocol(&g, &p, &b, &x, &mut rng);
+        JsProtocolRun {
+            g,
+            p,
+            b: b.0,
+            verified,
+        }
+    }
+
+    /// The generator `g`, truncated to 50 digits per component.
+    #[wasm_bindgen(getter)]
+    pub fn g(&self) -> String {
+        format!("{:.50}", self.g)
+    }
+
+    /// The modulus `p`, truncated to 50 digits per component.
+    #[wasm_bindgen(getter)]
+    pub fn p(&self) -> String {
+        format!("{:.50}", self.p)
+    }
+
+    /// Peggy's public value `b = g^x mod p`, truncated to 50 digits per component.
+    #[wasm_bindgen(getter)]
+    pub fn b(&self) -> String {
+        format!("{:.50}", self.b)
+    }
+
+    /// Whether Victor accepted Peggy's response.
+    #[wasm_bindgen(getter)]
+    pub fn verified(&self) -> bool {
+        self.verified
+    }
+}
+
+/// Runs one honest protocol round with `bits`-bit parameters drawn from `seed`.
+///
+/// # Returns
+/// `true` if the verification succeeds, `false` otherwise.
+#[wasm_bindgen]
+pub fn js_run_protocol(bits: u32, seed: u64) -> bool {
+    JsProtocolRun::new(bits, seed).verified
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_js_run_protocol_matches_core() {
+        assert!(js_run_protocol(64, 7));
+
+        // The wrapper draws p, g, x, then y, in that order from the seeded generator.
+        let mut rng = StdRng::seed_from_u64(7);
+        let p = generate_random_neutrosophic(&mut rng, 64);
+        let g = generate_random_neutrosophic(&mut rng, 64);
+        let x = SecretKey::generate(&mut rng, 64);
+        let b = x.public_key(&g, &p);
+        let run = JsProtocolRun::new(64, 7);
+        assert_eq!(run.g(), format!("{:.50}", g));
+        assert_eq!(run.p(), format!("{:.50}", p));
+        assert_eq!(run.b(), format!("{:.50}", b.0));
+        assert_eq!(
+            run.verified(),
+            neutrosophic_one_round_zkp_protocol(&g, &p, &b, &x, &mut rng)
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! Length-prefixed framing for sending `NeutrosophicNumber`s over a byte stream.
+//!
+//! Each frame is a 4-byte big-endian payload length followed by the `to_bytes_be`
+//! encoding of one number. The examples `tcp_verifier` and `tcp_prover` use it to
+//! run the protocol over TCP.
+
+use crate::neutrosophic_numbers::NeutrosophicNumber;
+use std::io::{self, Read, Write};
+
+/// The largest payload accepted by `read_number`, in bytes.
+///
+/// This comfortably fits two 4096-bit components and stops a peer from making the
+/// reader allocate an arbitrary amount of memory.
+pub const MAX_FRAME_LEN: u32 = 1 << 16;
+
+/// Writes `value` as a single frame and flushes the writer.
+///
+/// # Errors
+/// Returns `InvalidInput` if the encoding exceeds `MAX_FRAME_LEN`, and otherwise
+/// any error from the underlying writer.
+pub fn write_number<W: Write>(writer: &mut W, value: &NeutrosophicNumber) -> io::Result<()> {
+    let payload = value.to_bytes_be();
+    let len = u32::try_from(payload.len())
+        .ok()
+        .filter(|&len| len <= MAX_FRAME_LEN)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("frame of {} bytes exceeds MAX_FRAME_LEN", payload.len()),
+            )
+        })?;
+    writer.write_all(&len.to_be_bytes())?;
+    writer.write_all(&payload)?;
+    writer.flush()
+}
+
+/// Reads a single frame written by `write_number`.
+///
+/// # Errors
+/// Returns `InvalidData` if the announced length exceeds `MAX_FRAME_LEN` or the
+/// payload is not a valid `to_bytes_be` encoding, `UnexpectedEof` if the stream
+/// ends mid-frame, and otherwise any error from the underlying reader.
+pub fn read_number<R: Read>(reader: &mut R) -> io::Result<NeutrosophicNumber> {
+    let mut len_bytes = [0u8; 4];
+    reader.read_exact(&mut len_bytes)?;
+    let len = u32::from_be_bytes(len_bytes);
+    if len > MAX_FRAME_LEN {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("frame of {} bytes exceeds MAX_FRAME_LEN", len),
+        ));
+    }
+    let mut payload = vec![0u8; len as usize];
+    reader.read_exact(&mut payload)?;
+    NeutrosophicNumber::from_bytes_be(&payload)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_wire_round_trip() {
+        let values = [
+            NeutrosophicNumber::from((3, 2)),
+            NeutrosophicNumber::from((0, -65)),
+            NeutrosophicNumber::from((-1, 0)),
+        ];
+        let mut buffer = Vec::new();
+        for value in &values {
+            write_number(&mut buffer, value).unwrap();
+        }
+
+        let mut reader = Cursor::new(buffer);
+        for value in &values {
+            assert_eq!(&read_number(&mut reader).unwrap(), value);
+        }
+        let eof = read_number(&mut reader).unwrap_err();
+        assert_eq!(eof.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_wire_rejects_bad_frames() {
+        let oversized = (MAX_FRAME_LEN + 1).to_be_bytes();
+        let err = read_number(&mut Cursor::new(oversized)).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let mut truncated = Vec::new();
+        write_number(&mut truncated, &NeutrosophicNumber::from((3, 2))).unwrap();
+        truncated.pop();
+        let err = read_number(&mut Cursor::new(truncated)).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+        let garbage = [0, 0, 0, 2, 0xff, 0xff];
+        let err = read_number(&mut Cursor::new(garbage)).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,34 @@
+//! Reads the group parameters through the real process environment.
+//!
+//! The environment is process-global, so this file holds a single test and each
+//! step below runs in sequence.
+
+use n1rzkp::protocol::{ENV_G, ENV_P};
+use n1rzkp::{NeutrosophicError, NeutrosophicNumber, params_from_env};
+
+#[test]
+fn test_params_from_env() {
+    // SAFETY: this binary runs no other test and spawns no threads, so nothing
+    // reads or writes the environment concurrently.
+    unsafe {
+        std::env::set_var(ENV_G, "3 + 2I");
+        std::env::set_var(ENV_P, "7 + 4I");
+    }
+    let group = params_from_env().unwrap();
+    assert_eq!(group.g(), &NeutrosophicNumber::from((3, 2)));
+    assert_eq!(group.p(), &NeutrosophicNumber::from((7, 4)));
+
+    // SAFETY: as above.
+    unsafe { std::env::set_var(ENV_P, "seven") };
+    assert!(matches!(
+        params_from_env(),
+        Err(NeutrosophicError::ParseError(_))
+    ));
+
+    // SAFETY: as above.
+    unsafe { std::env::remove_var(ENV_P) };
+    assert_eq!(
+        params_from_env(),
+        Err(NeutrosophicError::MissingEnvVar(ENV_P.to_string()))
+    );
+}
@@ -0,0 +1,63 @@
+//! Checks the `log` records emitted by a protocol round.
+//!
+//! The logger is process-global, so this file holds a single test.
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use n1rzkp::{NeutrosophicNumber, Prover, PublicKey, SecretKey, Verifier, run_rounds};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::sync::Mutex;
+
+/// Records the level and message of every log record from this crate.
+struct CapturingLogger {
+    records: Mutex<Vec<(Level, String)>>,
+}
+
+impl Log for CapturingLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.target().starts_with("n1rzkp")
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            let message = record.args().to_string();
+            self.records.lock().unwrap().push((record.level(), message));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: CapturingLogger = CapturingLogger {
+    records: Mutex::new(Vec::new()),
+};
+
+#[test]
+fn protocol_steps_are_logged_in_order() {
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(LevelFilter::Debug);
+
+    let g = NeutrosophicNumber::from((3, 2));
+    let p = NeutrosophicNumber::from((7, 4));
+    let x = NeutrosophicNumber::from((4, 1));
+    let verifier = Verifier::new(g.clone(), p.clone(), PublicKey(g.pow_mod(&x, &p)));
+    let prover = Prover::new(SecretKey::new(x));
+    let mut rng = StdRng::seed_from_u64(7);
+    assert!(run_rounds(&prover, &verifier, 2, &mut rng));
+
+    let records = LOGGER.records.lock().unwrap();
+    let steps: Vec<(Level, &str)> = records
+        .iter()
+        .map(|(level, message)| (*level, message.split(':').next().unwrap()))
+        .collect();
+    let round = [
+        (Level::Debug, "challenge generated"),
+        (Level::Debug, "response computed"),
+        (Level::Info, "verification succeeded"),
+    ];
+    let mut expected = vec![(Level::Debug, "starting round 1 of 2")];
+    expected.extend(round);
+    expected.push((Level::Debug, "starting round 2 of 2"));
+    expected.extend(round);
+    assert_eq!(steps, expected);
+}
@@ -0,0 +1,26 @@
+//! Checks that the core arithmetic builds without the `std` feature.
+//!
+//! Runs `cargo build --lib --no-default-features` into a separate target directory,
+//! so the crate is compiled as `#![no_std]` with only `alloc`. To check against a
+//! target that has no `std` at all, run the same command by hand with e.g.
+//! `--target thumbv7em-none-eabihf`.
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn test_core_builds_without_std() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let status = Command::new(env!("CARGO"))
+        .args(["build", "--lib", "--no-default-features", "--quiet"])
+        .arg("--manifest-path")
+        .arg(manifest_dir.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(manifest_dir.join("target").join("no_std"))
+        .status()
+        .expect("failed to run cargo");
+    assert!(
+        status.success(),
+        "`cargo build --no-default-features` failed"
+    );
+}
@@ -0,0 +1,79 @@
+//! Runs the protocol with every indeterminate component of `g`, `p`, `x`, and `b`
+//! set to zero.
+//!
+//! With `p = q + 0I` both projections of the split representation reduce modulo the
+//! same real prime `q`, so the exchange degrades to the classic one-round protocol
+//! over the integers: `b = g^x mod q`, and Peggy's response projects onto
+//! `(g^y1)^x mod q`. Victor's `y` still has a nonzero indeterminate part, which only
+//! adds a second, independent exponentiation modulo `q`.
+
+use n1rzkp::neutrosophic_numbers::NeutrosophicNumber;
+use n1rzkp::{GroupParams, Prover, SecretKey, Verifier, run_rounds};
+use num_bigint::{BigInt, RandBigInt};
+use num_traits::Zero;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+/// Bit size of the secret exponent.
+const BIT_SIZE: u64 = 48;
+
+/// The Mersenne prime `2^61 - 1`.
+fn real_prime() -> BigInt {
+    (BigInt::from(1) << 61u32) - 1
+}
+
+/// A pure-real number `value + 0I`.
+fn real(value: BigInt) -> NeutrosophicNumber {
+    NeutrosophicNumber::new(value, BigInt::zero())
+}
+
+/// Pure-real group parameters and a pure-real secret drawn from `rng`.
+fn setup(rng: &mut StdRng) -> (GroupParams, NeutrosophicNumber) {
+    let group = GroupParams::new(real(BigInt::from(3)), real(real_prime())).unwrap();
+    let x = real(rng.gen_biguint(BIT_SIZE).into());
+    (group, x)
+}
+
+#[test]
+fn test_pure_real_pow_mod_matches_integer_modpow() {
+    let mut rng = StdRng::seed_from_u64(128);
+    let (group, x) = setup(&mut rng);
+    let q = real_prime();
+
+    let b = group.g().pow_mod(&x, group.p());
+    assert!(b.b.is_zero());
+    assert_eq!(b.a, BigInt::from(3).modpow(&x.a, &q));
+    assert!(group.p().is_probable_prime(20));
+}
+
+#[test]
+fn test_pure_real_honest_prover_succeeds() {
+    let mut rng = StdRng::seed_from_u64(129);
+    let (group, x) = setup(&mut rng);
+    let key = SecretKey::new(x.clone());
+    let verifier = Verifier::from_group(group.clone(), key.public_key_for(&group));
+    let prover = Prover::new(key);
+
+    // One round by hand, checked against the classic integer protocol.
+    let (c, y) = verifier.challenge(&mut rng);
+    let r = prover.respond(&c, group.p());
+    let q = real_prime();
+    let classic = BigInt::from(3).modpow(&y.expose().a, &q).modpow(&x.a, &q);
+    assert_eq!(r.determinate(), classic);
+    assert!(verifier.verify(y.expose(), &r));
+
+    assert!(run_rounds(&prover, &verifier, 10, &mut rng));
+}
+
+#[test]
+fn test_pure_real_dishonest_prover_fails() {
+    let mut rng = StdRng::seed_from_u64(130);
+    let (group, x) = setup(&mut rng);
+    let public_key = SecretKey::new(x.clone()).public_key_for(&group);
+    let verifier = Verifier::from_group(group, public_key);
+
+    let wrong_x = &x + &real(BigInt::from(1));
+    let impostor = Prover::new(SecretKey::new(wrong_x));
+    assert!(!run_rounds(&impostor, &verifier, 1, &mut rng));
+    assert!(!run_rounds(&impostor, &verifier, 10, &mut rng));
+}
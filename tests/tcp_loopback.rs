@@ -0,0 +1,62 @@
+//! Runs the message flow of the `tcp_verifier` and `tcp_prover` examples over a
+//! loopback socket, with Peggy on a separate thread.
+
+use n1rzkp::neutrosophic_numbers::NeutrosophicNumber;
+use n1rzkp::wire::{read_number, write_number};
+use n1rzkp::{Prover, PublicKey, SecretKey, Verifier};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+
+/// Plays Victor on an accepted connection and returns whether he accepts.
+fn run_verifier(listener: &TcpListener, seed: u64) -> bool {
+    let (mut stream, _) = listener.accept().unwrap();
+    let g = NeutrosophicNumber::from((3, 2));
+    let p = NeutrosophicNumber::from((101, 2));
+    write_number(&mut stream, &g).unwrap();
+    write_number(&mut stream, &p).unwrap();
+    let b = read_number(&mut stream).unwrap();
+
+    let verifier = Verifier::new(g, p, PublicKey(b));
+    let (c, y) = verifier.challenge(&mut StdRng::seed_from_u64(seed));
+    write_number(&mut stream, &c).unwrap();
+    let r = read_number(&mut stream).unwrap();
+    verifier.verify(y.expose(), &r)
+}
+
+/// Plays Peggy; a dishonest Peggy answers with a different key than she announced.
+fn run_prover(addr: std::net::SocketAddr, seed: u64, honest: bool) {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    let mut rng = StdRng::seed_from_u64(seed);
+    let g = read_number(&mut stream).unwrap();
+    let p = read_number(&mut stream).unwrap();
+    let key = SecretKey::generate(&mut rng, 64);
+    write_number(&mut stream, &key.public_key(&g, &p).0).unwrap();
+
+    let key = if honest {
+        key
+    } else {
+        SecretKey::generate(&mut rng, 64)
+    };
+    let c = read_number(&mut stream).unwrap();
+    write_number(&mut stream, &Prover::new(key).respond(&c, &p)).unwrap();
+}
+
+#[test]
+fn tcp_loopback_accepts_honest_prover() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let peggy = thread::spawn(move || run_prover(addr, 1, true));
+    assert!(run_verifier(&listener, 2));
+    peggy.join().unwrap();
+}
+
+#[test]
+fn tcp_loopback_rejects_dishonest_prover() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let peggy = thread::spawn(move || run_prover(addr, 3, false));
+    assert!(!run_verifier(&listener, 4));
+    peggy.join().unwrap();
+}
@@ -0,0 +1,79 @@
+//! Coarse timing regression test for `NeutrosophicNumber::ct_eq`.
+//!
+//! `Verifier::verify` compares Peggy's response against `b^y mod p` with `ct_eq`.
+//! A naive comparison returns as soon as the first byte differs, so an early
+//! mismatch is much faster than a match. This test times both cases and fails if
+//! their trimmed means differ by more than a generous tolerance. It cannot prove
+//! constant-time behavior, but it catches a regression to an early-exit comparison.
+
+use n1rzkp::neutrosophic_numbers::{NeutrosophicNumber, generate_random_neutrosophic_exact};
+use num_bigint::BigInt;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Bit size of the compared values, matching the demo's parameters.
+const BIT_SIZE: usize = 2048;
+/// Number of timed samples per case.
+const SAMPLES: usize = 300;
+/// Number of comparisons timed together in one sample, to rise above timer resolution.
+const CALLS_PER_SAMPLE: usize = 50;
+/// Number of untimed samples run first to warm up caches and the allocator.
+const WARMUP_SAMPLES: usize = 50;
+/// Fraction of samples dropped from each end before averaging.
+const TRIM: f64 = 0.2;
+/// Largest allowed ratio between the two trimmed means.
+const MAX_RATIO: f64 = 1.5;
+
+/// Times `CALLS_PER_SAMPLE` comparisons of `x` against `y`.
+fn sample(x: &NeutrosophicNumber, y: &NeutrosophicNumber) -> Duration {
+    let start = Instant::now();
+    for _ in 0..CALLS_PER_SAMPLE {
+        black_box(black_box(x).ct_eq(black_box(y)));
+    }
+    start.elapsed()
+}
+
+/// Returns the mean of the samples after dropping the `TRIM` fraction at each end.
+fn trimmed_mean(mut samples: Vec<Duration>) -> f64 {
+    samples.sort();
+    let cut = (samples.len() as f64 * TRIM) as usize;
+    let kept = &samples[cut..samples.len() - cut];
+    kept.iter().map(Duration::as_secs_f64).sum::<f64>() / kept.len() as f64
+}
+
+#[test]
+fn ct_eq_time_does_not_depend_on_mismatch_position() {
+    let mut rng = StdRng::seed_from_u64(71);
+    let response = generate_random_neutrosophic_exact(&mut rng, BIT_SIZE);
+    let matching = response.clone();
+    // Differs in the most and least significant bytes of `a`, so an early-exit
+    // comparison scanning in either direction would stop at once.
+    let flip = (BigInt::from(0xff) << (BIT_SIZE - 8)) | BigInt::from(0xff);
+    let mismatching = NeutrosophicNumber::new(&response.a ^ flip, response.b.clone());
+    assert!(response.ct_eq(&matching));
+    assert!(!response.ct_eq(&mismatching));
+
+    for _ in 0..WARMUP_SAMPLES {
+        sample(&response, &matching);
+        sample(&response, &mismatching);
+    }
+
+    // Interleave the two cases so that drift in machine load affects both equally.
+    let mut matching_samples = Vec::with_capacity(SAMPLES);
+    let mut mismatching_samples = Vec::with_capacity(SAMPLES);
+    for _ in 0..SAMPLES {
+        matching_samples.push(sample(&response, &matching));
+        mismatching_samples.push(sample(&response, &mismatching));
+    }
+
+    let matching_mean = trimmed_mean(matching_samples);
+    let mismatching_mean = trimmed_mean(mismatching_samples);
+    let ratio = matching_mean.max(mismatching_mean) / matching_mean.min(mismatching_mean);
+    assert!(
+        ratio < MAX_RATIO,
+        "ct_eq timing differs between matching ({matching_mean:e}s) and mismatching \
+         ({mismatching_mean:e}s) inputs by a factor of {ratio:.2}"
+    );
+}
@@ -0,0 +1,45 @@
+//! Checks `pow_mod` against the fixed test vectors in `tests/vectors.json`.
+//!
+//! Each vector lists `g`, `x`, `p`, and the expected `b = g^x mod p`, with a note
+//! showing the hand computation. Requires the `serde` feature:
+//! `cargo test --features serde --test vectors`.
+
+use n1rzkp::neutrosophic_numbers::NeutrosophicNumber;
+use serde::Deserialize;
+
+/// One `pow_mod` test vector.
+#[derive(Deserialize)]
+struct Vector {
+    /// The hand computation of the expected result.
+    note: String,
+    g: NeutrosophicNumber,
+    x: NeutrosophicNumber,
+    p: NeutrosophicNumber,
+    b: NeutrosophicNumber,
+}
+
+/// Loads the vectors from `tests/vectors.json`.
+fn load_vectors() -> Vec<Vector> {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/vectors.json");
+    let json = std::fs::read_to_string(path).expect("failed to read tests/vectors.json");
+    serde_json::from_str(&json).expect("tests/vectors.json is malformed")
+}
+
+#[test]
+fn pow_mod_matches_vectors() {
+    let vectors = load_vectors();
+    assert!(!vectors.is_empty());
+    for vector in &vectors {
+        assert_eq!(
+            vector.g.pow_mod(&vector.x, &vector.p),
+            vector.b,
+            "vector failed: {}",
+            vector.note
+        );
+    }
+}
+
+#[test]
+fn vectors_include_a_negative_indeterminate_result() {
+    assert!(load_vectors().iter().any(|vector| vector.b.b < 0.into()));
+}